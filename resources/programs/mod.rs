@@ -1,7 +0,0 @@
-use crate::schedule::Program;
-mod prog_chem_ba;
-mod prog_phys_ba;
-
-pub fn programs() -> Vec<Program> {
-    vec![prog_chem_ba::prog(), prog_phys_ba::prog()]
-}
@@ -0,0 +1,40 @@
+import json
+import pathlib
+import re
+
+CATALOG_PATH = pathlib.Path(__file__).parent.parent.joinpath("catalogs", "2025-2026.json")
+
+COURSE_CODE = re.compile(r"[A-Z]+-#?[0-9A-Z]+")
+
+
+def load_catalog(path=CATALOG_PATH) -> dict:
+    with open(path, encoding="utf-8") as f:
+        return json.load(f)
+
+
+def save_catalog(catalog: dict, path=CATALOG_PATH):
+    """Write the catalog back in the layout ROSS and `git diff` expect: sorted keys, 2-space indent."""
+    with open(path, "w", encoding="utf-8") as f:
+        json.dump(catalog, f, indent=2, sort_keys=True)
+
+
+def referenced_codes(catalog: dict) -> set[str]:
+    """Every course code the catalog's programs, prerequisites and geneds mention."""
+    codes = set(catalog["prereqs"])
+
+    def walk(node):
+        if isinstance(node, str):
+            if COURSE_CODE.fullmatch(node):
+                codes.add(node)
+        elif isinstance(node, list):
+            for item in node:
+                walk(item)
+        elif isinstance(node, dict):
+            for value in node.values():
+                walk(value)
+
+    for program in catalog["programs"]:
+        walk([program.get("semesters", []), program.get("required", []), program.get("electives", [])])
+    walk(catalog["prereqs"])
+    walk(catalog["geneds"])
+    return codes
@@ -1,13 +1,14 @@
-import json
 import logging
 import random
-import subprocess
+import re
 import time
 
 import requests
 import requests_cache
 from bs4 import BeautifulSoup
 
+from catalog_json import load_catalog, referenced_codes, save_catalog
+
 # Configure logging
 logging.basicConfig(level=logging.WARNING, format="%(asctime)s - %(levelname)s - %(message)s")
 logger = logging.getLogger(__name__)
@@ -37,84 +38,72 @@ def course_lookup(code: str) -> dict[str]:
     course_code = course_section.find("span", class_="detail-code").get_text(strip=True)
     title = course_section.find("span", class_="detail-title").get_text(strip=True)
     credits_raw = course_section.find("span", class_="detail-hours_html").get_text(strip=True)
-    credits = credits_raw.strip("()").split()[0]  # Extract just the number
+    credits = credits_raw.strip("()").split()[0]  # Extract just the number, or a range like "1–3"
     term_raw = course_section.find("span", class_="detail-term").get_text(strip=True)
-    term = term_raw.strip("()").split()[0]
+    term = parse_term(term_raw)
 
     return {
         "code": course_code,
         "title": title,
-        "credits": int(credits) if credits.upper().strip() != "NULL" else None,
+        "credits": parse_credits(credits),
         "url": url,
         "term": term,
     }
 
 
-def format_code(course) -> str:
-    return course["stem"].upper() + "-" + str(list(course["code"].values())[0]).upper()
-
-
-PREAMBLE = """
-use crate::{CC, schedule::{CourseCode, CourseTermOffering::{self, *}}};
-use std::collections::HashMap;
-
-pub fn courses() -> HashMap<CourseCode, (String, Option<u32>, CourseTermOffering)> {
-    HashMap::from([
-"""
-
-POSTAMBLE = "])\n}\n"
-
-
-def repr_rs(x: str) -> str:
-    return repr(x).replace("'", '"')
-
-
-def format_course(course, assoc_info) -> str:
-    title, credits, term = assoc_info
-    title = title.replace('"', '\\"')
-    if credits is None:
-        credits_str = "None"
+def parse_term(term_raw: str) -> str:
+    """Map a catalog term such as "Fall, odd years" to a CourseTermOffering variant name."""
+    # Also splits already-converted names like "FallOdd"
+    words = [w.lower() for w in re.findall(r"[A-Z]?[a-z]+", term_raw)]
+    parity = next((w.title() for w in words if w in ("odd", "even")), None)
+    if "fall" in words and "spring" in words:
+        season = "Both"
     else:
-        credits_str = f"Some({credits})"
-
-    return f"({format_cc(course)} ({repr_rs(title)}.into(), {credits_str}, {term.title()})),\n"
-
-
-def format_cc(course) -> str:
-    stem, code = course.split("-")
-    if code.isnumeric():
-        code = int(code)
-
-    return f"CC!({repr_rs(stem)}, {repr_rs(code)}),"
+        season = next(
+            (
+                w.title()
+                for w in words
+                if w in ("fall", "spring", "summer", "winter", "both", "discretion", "infrequently")
+            ),
+            None,
+        )
+    if parity and season in ("Fall", "Spring"):
+        return season + parity
+    if parity and season in (None, "Both"):
+        return parity + "Years"
+    if season:
+        return season
+    return words[0].title() if words else term_raw
+
+
+def parse_credits(credits: str):
+    """Credits as an int, a [min, max] list for variable-credit courses, or None."""
+    if credits.upper().strip() == "NULL":
+        return None
+    # The catalog writes ranges with an en dash, as in "1–3"
+    parts = re.split(r"\s*[-–]\s*", credits.strip())
+    if len(parts) == 2:
+        return [int(parts[0]), int(parts[1])]
+    return int(credits)
 
 
 def main():
-    with open("../script_assistant/new_courses.json") as file:
-        courses = json.load(file)
-    with open("../script_assistant/old_courses.json") as file:
-        export_courses = json.load(file)
-    for course in courses:
-        code = format_code(course)
+    # Look up every course the catalog mentions but doesn't list yet
+    catalog = load_catalog()
+    for code in sorted(referenced_codes(catalog) - set(catalog["courses"])):
         try:
             course_info = course_lookup(code)
             print(
                 f"Course: {course_info['code']}, Title: {course_info['title']}, Credits: {course_info['credits']}, Term: {course_info['term']}, URL: {course_info['url']}"
             )
-            export_courses.append(
-                (format_code(course), (course_info["title"], course_info["credits"], course_info["term"]))
-            )
+            catalog["courses"][code] = [course_info["title"], course_info["credits"], course_info["term"]]
         except requests.RequestException as e:
             logger.error("Failed to fetch course %s: %s", code, e)
         except Exception as e:
             logger.error("An error occurred while processing course %s: %s", code, e)
         else:
             time.sleep(random.randrange(1, 15) / 100)  # Sleep to avoid overwhelming the server
-    with open("../resources/courses.rs", "w", encoding="utf-8") as f:
-        f.write(PREAMBLE)
-        for course, assoc_info in sorted(export_courses, key=lambda x: x[0]):
-            f.write(format_course(course, assoc_info))
-        f.write(POSTAMBLE)
-    subprocess.run(["rustfmt", "../resources/courses.rs"], check=True)
+    save_catalog(catalog)
 
 
 if __name__ == "__main__":
@@ -1,3 +1,4 @@
+import json
 import logging
 import sys
 
@@ -38,21 +39,10 @@ def gened_lookup(gened: str) -> list[str]:
     return courses
 
 
-def repr_rs(x: str) -> str:
-    return repr(x).replace("'", '"')
-
-
-def format_cc(course) -> str:
-    stem, code = course.split("-")
-    if code.isnumeric():
-        code = int(code)
-
-    return f"CC!({repr_rs(stem)}, {repr_rs(code)}),"
-
-
 def main():
+    # Printed as a JSON list of course codes, ready for a gened's `courses` in the catalog
     courses = gened_lookup(sys.argv[1])
-    print(*[format_cc(course) for course in courses])
+    print(json.dumps([course.upper() for course in courses]))
 
 
 if __name__ == "__main__":
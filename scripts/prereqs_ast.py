@@ -1,39 +1,9 @@
 import ast
-import itertools
-import pathlib
 import re
-import subprocess
 
-PATH = pathlib.Path(__file__).parent.parent.joinpath("resources", "course_reqs")
+from catalog_json import load_catalog, save_catalog
 
-BASE_FILENAME = "mod.rs"
-
-BASE_PREAMBLE = """
-use crate::prereqs::CourseReq;
-use crate::schedule::CourseCode;
-use std::collections::HashMap;
-use std::iter::empty;
-
-"""
-
-BASE_MIDAMBLE = "\npub fn prereqs() -> HashMap<CourseCode, CourseReq> { empty()"
-
-BASE_POSTAMBLE = ".collect()}"
-
-PREAMBLE = """
-#![allow(unused_imports)]
-
-use crate::prereqs::{
-    CourseReq::{self, *},
-    Grade, GradeLetter, GradeQualifier,
-};
-use crate::schedule::CourseCode;
-use crate::{CC, GR};
-
-pub fn prereqs() -> Vec<(CourseCode, CourseReq)> { vec![
-"""
-
-POSTAMBLE = "]}"
+GRADE_QUALIFIERS = {"+": "Plus", "-": "Minus", "": "None"}
 
 
 def strip_quotes(s):
@@ -42,18 +12,21 @@ def strip_quotes(s):
     return s
 
 
-def rustify(node, default_stem, in_tuple=False, co_prefix=False):
+def to_grade(grade: str) -> dict:
+    grade = strip_quotes(grade).upper()
+    return {"letter": grade[0], "qualifier": GRADE_QUALIFIERS[grade[1:]]}
+
+
+def to_req(node, default_stem, in_tuple=False, co_prefix=False):
+    """Convert a parsed requirement into a catalog `CourseReq`, or a bare string inside tuples."""
+
     # Helper to wrap with PreCourse/CoCourse or their Grade variants
     def wrap_course(stem, code, grade=None, co_prefix=False):
-        if isinstance(code, str) and not code.isnumeric():
-            code = '"' + code.upper() + '"'
-        stem = stem.upper()
-        cc = f'CC!("{stem}", {code})'
+        cc = f"{stem.upper()}-{str(code).upper()}"
         if grade is not None:
-            gr = grade if grade.startswith("GR!") else f"GR!({grade.upper()})"
-            return f"{'CoCourseGrade' if co_prefix else 'PreCourseGrade'}({cc}, {gr})"
+            return {"CoCourseGrade" if co_prefix else "PreCourseGrade": [cc, to_grade(grade)]}
         else:
-            return f"{'CoCourse' if co_prefix else 'PreCourse'}({cc})"
+            return {"CoCourse" if co_prefix else "PreCourse": cc}
 
     # Helper to parse STEM-CODE format
     def parse_stem_code(course_str, default_stem):
@@ -70,19 +43,19 @@ def rustify(node, default_stem, in_tuple=False, co_prefix=False):
         func = func.title()
         args = node.args
         if func == "And" or func == "Or":
-            sub = [rustify(arg, default_stem) for arg in args]
-            return f"{func}(vec![{', '.join(sub)}])"
+            return {func: [to_req(arg, default_stem) for arg in args]}
         if func == "Co":
             # Co(...) just sets co_prefix for the inner logic
-            return rustify(ast.Tuple(elts=args, ctx=ast.Load()), default_stem, co_prefix=True)
+            return to_req(ast.Tuple(elts=args, ctx=ast.Load()), default_stem, co_prefix=True)
         if func.upper() == "GR":
-            return f"GR!({', '.join(rustify(arg, default_stem) for arg in args)})"
+            # The grade itself; the enclosing tuple turns it into a `Grade`
+            return to_req(args[0], default_stem, in_tuple=True)
     elif isinstance(node, ast.Tuple):
         elts = node.elts
         if len(elts) == 2:
             # (STEM-CODE, GRADE) or (CODE, GRADE)
-            first_val = rustify(elts[0], default_stem, in_tuple=True)
-            second_val = rustify(elts[1], default_stem, in_tuple=True)
+            first_val = to_req(elts[0], default_stem, in_tuple=True)
+            second_val = to_req(elts[1], default_stem, in_tuple=True)
 
             # Check if second element is a grade
             if (
@@ -93,7 +66,7 @@ def rustify(node, default_stem, in_tuple=False, co_prefix=False):
                 # Second element is a grade
                 stem, code = parse_stem_code(first_val, default_stem)
                 return wrap_course(stem, code, second_val, co_prefix)
-            elif isinstance(elts[1], ast.Call) and getattr(elts[1].func, "id", None) == "GR":
+            elif isinstance(elts[1], ast.Call) and getattr(elts[1].func, "id", "").upper() == "GR":
                 # Second element is a GR() call
                 stem, code = parse_stem_code(first_val, default_stem)
                 return wrap_course(stem, code, second_val, co_prefix)
@@ -102,7 +75,7 @@ def rustify(node, default_stem, in_tuple=False, co_prefix=False):
                 raise ValueError(f"Unsupported 2-tuple format: {ast.dump(node)}")
         elif len(elts) == 1:
             # (STEM-CODE,) or (CODE,)
-            course_val = rustify(elts[0], default_stem, in_tuple=True)
+            course_val = to_req(elts[0], default_stem, in_tuple=True)
             stem, code = parse_stem_code(course_val, default_stem)
             return wrap_course(stem, code, None, co_prefix)
         else:
@@ -114,25 +87,30 @@ def rustify(node, default_stem, in_tuple=False, co_prefix=False):
             else:
                 return wrap_course(default_stem, node.value, None, co_prefix=co_prefix)
         elif isinstance(node.value, str):
-            # If it looks like a grade, just return the string (parent will wrap in GR!)
+            # If it looks like a grade, just return the string (parent will make it a `Grade`)
             if re.fullmatch(r"[A-DF][+-]?", node.value):
                 return node.value
             # Handle STEM-CODE format or plain course code
             elif in_tuple:
-                return f'"{node.value}"'
+                return node.value
             else:
                 # Parse as STEM-CODE or use default stem
                 stem, code = parse_stem_code(node.value, default_stem)
                 return wrap_course(stem, code, None, co_prefix=co_prefix)
     elif isinstance(node, ast.Name):
-        if node.id.title() in {"Instructor", "None"}:
-            return node.id.title()
+        if node.id.title() == "Instructor":
+            return "Instructor"
+        elif node.id.title() == "None":
+            return "NotRequired"
+        elif node.id.title() in STANDINGS:
+            # Minimum class standing; abbreviations are expanded by `preprocess_standing`
+            return {"Standing": node.id.title()}
         elif node.id.title() == "Prog":
             # Majors or Programs of Distinction (or say Nursing School etc.)
             # This will get calculated on the program's end with `assoc_stems`
-            return f'Program("{default_stem}".into())'
+            return {"Program": default_stem}
         if in_tuple:
-            return f'"{node.id}"'
+            return node.id
         # Parse as STEM-CODE or use default stem
         stem, code = parse_stem_code(node.id, default_stem)
         return wrap_course(stem, code, None, co_prefix=co_prefix)
@@ -150,9 +128,8 @@ def rustify(node, default_stem, in_tuple=False, co_prefix=False):
                 raise ValueError(f"Unsupported BinOp right side: {ast.dump(node.right)}")
 
             if in_tuple:
-                # For tuples, return as a quoted string in STEM-CODE format
-                code_str = code  # .strip('"') if isinstance(node.right, ast.Name) else code
-                return f'"{stem}-{code_str}"'
+                # For tuples, return as a string in STEM-CODE format
+                return f"{stem}-{code}"
             else:
                 return wrap_course(stem, code, None, co_prefix=co_prefix)
         else:
@@ -161,8 +138,31 @@ def rustify(node, default_stem, in_tuple=False, co_prefix=False):
 
 
 def parse_req(req, default_stem):
-    expr = ast.parse(preprocess_grades(req), mode="eval").body
-    return rustify(expr, default_stem)
+    expr = ast.parse(preprocess_grades(preprocess_standing(req)), mode="eval").body
+    return to_req(expr, default_stem)
+
+
+STANDINGS = ["Freshman", "Sophomore", "Junior", "Senior"]
+
+STANDING_ABBREVS = {
+    "Fr": "Freshman",
+    "So": "Sophomore",
+    "Ju": "Junior",
+    "Jr": "Junior",
+    "Sen": "Senior",
+    "Sr": "Senior",
+}
+
+
+def preprocess_standing(expr: str) -> str:
+    # "Sen", "Ju/Sen" and "Ju+" become the lowest standing listed: Senior, Junior and Junior
+    abbrev = "|".join(STANDING_ABBREVS)
+
+    def expand(m):
+        names = [STANDING_ABBREVS[a] for a in m.group(0).rstrip("+").split("/")]
+        return min(names, key=STANDINGS.index)
+
+    return re.sub(rf"\b(?:{abbrev})(?:/(?:{abbrev}))*\+?(?![\w-])", expand, expr)
 
 
 def preprocess_grades(expr: str) -> str:
@@ -172,65 +172,44 @@ def preprocess_grades(expr: str) -> str:
     return expr.strip()
 
 
-class StopMeError(Exception):
-    pass
+def course_key(stem: str, code: str) -> str:
+    code = strip_quotes(code).upper()
+    return code if "-" in code else f"{stem.upper()}-{code}"
 
 
 # Example usage:
 def main():
-    # if PATH.exists():
-    #     resp = input(f"File {PATH} exists. Overwrite? [Y/n] ").strip().lower()
-    #     if resp != "y":
-    #         print("Aborting.")
-    #         return
-    with open(PATH.joinpath(BASE_FILENAME), "w") as base_f:
-        base_f.write(BASE_PREAMBLE)
-        print("Rust CourseReq REPL. Type 'stem STEM' to set stem, 'exit' to quit.")
-        try:
-            stem = input("Stem? ")
+    # Entries are added to (or replace those already in) the catalog's prerequisites; every
+    # other course is left alone
+    catalog = load_catalog()
+    print("CourseReq REPL. Type 'stem STEM' to set stem, 'exit' to quit.")
+    try:
+        stem = input("Stem? ").strip().upper()
+        while True:
+            inp = input(f"[stem={stem}] code> ").strip()
+            if inp.lower() == "exit":
+                break
+            if inp.lower().startswith("stem "):
+                stem = inp.split()[1].upper()
+                continue
+            code = course_key(stem, inp)
             while True:
-                with open(PATH.joinpath("TEMP.rs").with_stem(f"stem_{stem.lower()}"), "w") as f:
-                    f.write(PREAMBLE)
-
-                    try:
-                        while True:
-                            inp = input(f"[stem={stem}] code> ").strip()
-                            if inp.lower() == "exit":
-                                raise StopMeError
-                            if inp.lower().startswith("stem "):
-                                stem = inp.split()[1].upper()
-                                break
-                            # if not inp.isdigit():
-                            #     print("Enter a course code (e.g., 1020) or 'stem STEM'.")
-                            #     continue
-                            code = inp
-                            while True:
-                                req = input(f"[stem={stem}] req for {code}> ").strip()
-                                if not req:
-                                    print("No requirement entered, skipping.")
-                                    break
-                                if stem:
-                                    try:
-                                        parsed_req = parse_req(req, stem)
-                                    except SyntaxError as e:
-                                        print(f"Error parsing requirement: {e}")
-                                        continue
-                                    print("Added course!")
-                                    f.write(f'(\n    CC!("{stem}", {code}),\n    {parsed_req},\n),\n')
-                                    break
-                    finally:
-                        f.write(POSTAMBLE)
-        except StopMeError:
-            pass
-        finally:
-            for i in PATH.glob("stem_*.rs"):
-                base_f.write(f"mod stem_{i.stem[5:].lower()};\n")
-            base_f.write(BASE_MIDAMBLE)
-            for i in PATH.glob("stem_*.rs"):
-                base_f.write(f".chain(stem_{i.stem[5:].lower()}::prereqs().into_iter())\n")
-            base_f.write(BASE_POSTAMBLE)
-    for i in itertools.chain(PATH.glob("stem_*.rs"), [PATH.joinpath(BASE_FILENAME)]):
-        subprocess.run(["rustfmt", str(i)], check=True)
+                req = input(f"[stem={stem}] req for {code}> ").strip()
+                if not req:
+                    print("No requirement entered, skipping.")
+                    break
+                try:
+                    parsed_req = parse_req(req, stem)
+                except SyntaxError as e:
+                    print(f"Error parsing requirement: {e}")
+                    continue
+                print("Added course!")
+                catalog["prereqs"][code] = parsed_req
+                break
+    except EOFError:
+        pass
+    finally:
+        save_catalog(catalog)
 
 
 if __name__ == "__main__":
@@ -0,0 +1,343 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::prereqs::CourseReq;
+use crate::schedule::{Catalog, CourseCode, CreditRange};
+
+/// How the requirements a student is held to change when they move from one catalog year to
+/// another; empty when nothing that affects them changed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CatalogDiff {
+    /// `low_year` of the catalog moved from
+    pub from: u32,
+    /// `low_year` of the catalog moved to
+    pub to: u32,
+    pub programs: Vec<ProgramDiff>,
+    pub geneds: Vec<GenEdChange>,
+    pub prereqs: Vec<PrereqChange>,
+    pub credits: Vec<CreditChange>,
+    /// Courses in the student's plan that the newer catalog no longer lists
+    pub removed_courses: Vec<CourseCode>,
+}
+
+/// Changes to one of the student's programs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramDiff {
+    pub name: String,
+    pub added_courses: Vec<CourseCode>,
+    pub removed_courses: Vec<CourseCode>,
+    pub electives_changed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenEdChange {
+    Added(String),
+    Removed(String),
+    /// Same name, different requirement or kind
+    Changed(String),
+}
+
+/// A course in the student's plan whose prerequisites changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrereqChange {
+    pub course: CourseCode,
+    pub old: CourseReq,
+    pub new: CourseReq,
+}
+
+/// A course in the student's plan whose credits changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreditChange {
+    pub course: CourseCode,
+    pub old: Option<CreditRange>,
+    pub new: Option<CreditRange>,
+}
+
+impl ProgramDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_courses.is_empty() && self.removed_courses.is_empty() && !self.electives_changed
+    }
+}
+
+/// Compare the requirements of `programs` between two catalogs, along with the prerequisites
+/// and credits of `courses` (usually every course in the student's plan).
+pub fn diff_catalogs(
+    old: &Catalog,
+    new: &Catalog,
+    programs: &[String],
+    courses: &HashSet<&CourseCode>,
+) -> CatalogDiff {
+    let mut diff = CatalogDiff {
+        from: old.low_year,
+        to: new.low_year,
+        ..Default::default()
+    };
+
+    for name in programs {
+        let old_prog = old.programs.iter().find(|p| p.name == *name);
+        let new_prog = new.programs.iter().find(|p| p.name == *name);
+        let (Some(old_prog), Some(new_prog)) = (old_prog, new_prog) else {
+            continue;
+        };
+        let old_required: HashSet<&CourseCode> = old_prog.required_courses().collect();
+        let new_required: HashSet<&CourseCode> = new_prog.required_courses().collect();
+        let mut added_courses: Vec<CourseCode> = new_required
+            .difference(&old_required)
+            .map(|c| (*c).clone())
+            .collect();
+        let mut removed_courses: Vec<CourseCode> = old_required
+            .difference(&new_required)
+            .map(|c| (*c).clone())
+            .collect();
+        added_courses.sort_by_key(|c| c.to_string());
+        removed_courses.sort_by_key(|c| c.to_string());
+        let prog_diff = ProgramDiff {
+            name: name.clone(),
+            added_courses,
+            removed_courses,
+            electives_changed: old_prog.electives != new_prog.electives,
+        };
+        if !prog_diff.is_empty() {
+            diff.programs.push(prog_diff);
+        }
+    }
+
+    for gened in &new.geneds {
+        match old.geneds.iter().find(|g| g.name() == gened.name()) {
+            None => diff
+                .geneds
+                .push(GenEdChange::Added(gened.name().to_string())),
+            Some(old_gened) if old_gened != gened => diff
+                .geneds
+                .push(GenEdChange::Changed(gened.name().to_string())),
+            Some(_) => {}
+        }
+    }
+    for gened in &old.geneds {
+        if !new.geneds.iter().any(|g| g.name() == gened.name()) {
+            diff.geneds
+                .push(GenEdChange::Removed(gened.name().to_string()));
+        }
+    }
+
+    let mut courses: Vec<&CourseCode> = courses.iter().copied().collect();
+    courses.sort_by_key(|c| c.to_string());
+    for code in courses {
+        let old_req = old.prereqs.get(code).cloned().unwrap_or_default();
+        let new_req = new.prereqs.get(code).cloned().unwrap_or_default();
+        if old_req != new_req {
+            diff.prereqs.push(PrereqChange {
+                course: code.clone(),
+                old: old_req,
+                new: new_req,
+            });
+        }
+        match (old.courses.get(code), new.courses.get(code)) {
+            (Some(_), None) => diff.removed_courses.push(code.clone()),
+            (Some((_, old_cr, _)), Some((_, new_cr, _))) if old_cr != new_cr => {
+                diff.credits.push(CreditChange {
+                    course: code.clone(),
+                    old: *old_cr,
+                    new: *new_cr,
+                })
+            }
+            _ => {}
+        }
+    }
+    diff
+}
+
+impl CatalogDiff {
+    pub fn is_empty(&self) -> bool {
+        self.programs.is_empty()
+            && self.geneds.is_empty()
+            && self.prereqs.is_empty()
+            && self.credits.is_empty()
+            && self.removed_courses.is_empty()
+    }
+
+    /// One line per change, in a stable order.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for prog in &self.programs {
+            for code in &prog.added_courses {
+                lines.push(format!("{}: now requires {}", prog.name, code));
+            }
+            for code in &prog.removed_courses {
+                lines.push(format!("{}: no longer requires {}", prog.name, code));
+            }
+            if prog.electives_changed {
+                lines.push(format!("{}: electives changed", prog.name));
+            }
+        }
+        for change in &self.geneds {
+            lines.push(change.to_string());
+        }
+        for change in &self.prereqs {
+            lines.push(change.to_string());
+        }
+        for change in &self.credits {
+            lines.push(change.to_string());
+        }
+        for code in &self.removed_courses {
+            lines.push(format!("{} is no longer offered", code));
+        }
+        lines
+    }
+}
+
+impl fmt::Display for CatalogDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(
+                f,
+                "No requirement changes from the {} to the {} catalog",
+                self.from, self.to
+            );
+        }
+        write!(
+            f,
+            "Requirement changes from the {} to the {} catalog:",
+            self.from, self.to
+        )?;
+        for line in self.lines() {
+            write!(f, "\n  - {}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GenEdChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenEdChange::Added(name) => write!(f, "{}: new gened", name),
+            GenEdChange::Removed(name) => write!(f, "{}: gened removed", name),
+            GenEdChange::Changed(name) => write!(f, "{}: gened requirement changed", name),
+        }
+    }
+}
+
+impl fmt::Display for PrereqChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: prerequisites changed from ({}) to ({})",
+            self.course, self.old, self.new
+        )
+    }
+}
+
+impl fmt::Display for CreditChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let credits =
+            |cr: Option<CreditRange>| cr.map_or("unknown".to_string(), |cr| cr.to_string());
+        write!(
+            f,
+            "{}: credits changed from {} to {}",
+            self.course,
+            credits(self.old),
+            credits(self.new)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::tests::{catalog, code};
+    use serde_json::json;
+
+    fn composition(courses: serde_json::Value) -> serde_json::Value {
+        json!({"Core": {"name": "English Composition", "req": {"Courses": {"courses": courses, "num": 1}}}})
+    }
+
+    fn catalogs() -> (Catalog, Catalog) {
+        let old = catalog(json!({
+            "programs": [{"name": "BS Physics", "kind": "Major",
+                          "semesters": [["PHYS-2100", "MATH-1300"]]}],
+            "geneds": [composition(json!(["ENGL-1000"]))],
+            "prereqs": {"PHYS-2100": {"PreCourse": "MATH-1300"}},
+            "courses": {
+                "PHYS-2100": ["University Physics I", 4, "Fall"],
+                "MATH-1300": ["Calculus I", 4, "Both"],
+                "PHYS-4900": ["Research", [1, 3], "Both"],
+                "PHYS-3000": ["Modern Physics", 3, "Spring"]
+            }
+        }));
+        let new = catalog(json!({
+            "programs": [{"name": "BS Physics", "kind": "Major",
+                          "semesters": [["PHYS-2100", "MATH-1300", "PHYS-3000"]]}],
+            "geneds": [composition(json!(["ENGL-1000", "ENGL-1010"]))],
+            "prereqs": {"PHYS-2100": {"CoCourse": "MATH-1300"}},
+            "courses": {
+                "PHYS-2100": ["University Physics I", 4, "Fall"],
+                "MATH-1300": ["Calculus I", null, "Both"],
+                "PHYS-4900": ["Research", [1, 4], "Both"]
+            },
+            "low_year": 2026
+        }));
+        (old, new)
+    }
+
+    #[test]
+    fn only_changes_to_planned_courses_are_reported() {
+        let (old, new) = catalogs();
+        let plan = [code("PHYS-2100"), code("MATH-1300"), code("PHYS-4900")];
+        let diff = diff_catalogs(
+            &old,
+            &new,
+            &["BS Physics".to_string()],
+            &plan.iter().collect(),
+        );
+        assert_eq!(
+            diff.programs,
+            vec![ProgramDiff {
+                name: "BS Physics".to_string(),
+                added_courses: vec![code("PHYS-3000")],
+                removed_courses: vec![],
+                electives_changed: false,
+            }]
+        );
+        assert_eq!(
+            diff.geneds,
+            vec![GenEdChange::Changed("English Composition".to_string())]
+        );
+        assert_eq!(diff.prereqs.len(), 1);
+        assert_eq!(diff.prereqs[0].course, code("PHYS-2100"));
+        // PHYS-3000 left the catalog, but it isn't in the plan
+        assert!(diff.removed_courses.is_empty());
+        assert_eq!(
+            diff.lines()[3..],
+            [
+                "MATH-1300: credits changed from 4 to unknown",
+                "PHYS-4900: credits changed from 1-3 to 1-4",
+            ]
+        );
+    }
+
+    #[test]
+    fn planned_courses_no_longer_listed_are_removed() {
+        let (old, new) = catalogs();
+        let plan = [code("PHYS-3000")];
+        let diff = diff_catalogs(&old, &new, &[], &plan.iter().collect());
+        assert_eq!(diff.removed_courses, vec![code("PHYS-3000")]);
+        assert!(diff.credits.is_empty());
+    }
+
+    #[test]
+    fn identical_catalogs_have_no_changes() {
+        let (old, _) = catalogs();
+        let plan = [code("PHYS-2100")];
+        let diff = diff_catalogs(
+            &old,
+            &old,
+            &["BS Physics".to_string()],
+            &plan.iter().collect(),
+        );
+        assert!(diff.is_empty());
+        assert_eq!(
+            diff.to_string(),
+            "No requirement changes from the 2025 to the 2025 catalog"
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::prereq_graph::PrereqGraph;
+use crate::prereqs::CourseReq;
+use crate::schedule::{Catalog, CourseCode, CreditLimits, CreditPolicy, ProgramKind};
+use crate::terms::{Season, Term};
+
+/// Everything wrong with a catalog's data; empty when the catalog is clean.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LintReport {
+    pub dangling_codes: Vec<DanglingCode>,
+    /// Courses that are, through their required prerequisites, prerequisites of themselves
+    pub prereq_cycles: Vec<Vec<CourseCode>>,
+    /// Courses that can never be taken, given their prerequisites and term offerings
+    pub unsatisfiable_courses: Vec<CourseCode>,
+    pub template_violations: Vec<TemplateViolation>,
+    pub short_templates: Vec<ShortTemplate>,
+    pub kind_mismatches: Vec<KindMismatch>,
+}
+
+/// A course code referenced somewhere in the catalog but missing from `Catalog::courses`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingCode {
+    pub code: CourseCode,
+    /// Where the code is referenced, e.g. "BA Physics" or "prerequisites of PHYS-2100"
+    pub referenced_by: String,
+}
+
+/// A course placed in a program's template before its own prerequisites.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateViolation {
+    pub program: String,
+    pub course: CourseCode,
+    /// Index into `Program::semesters`
+    pub semester: usize,
+    pub req: CourseReq,
+}
+
+/// A program whose template has fewer semesters than the prerequisite chain to its capstone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortTemplate {
+    pub program: String,
+    /// Semesters in the template
+    pub semesters: usize,
+    /// The critical path to the capstone, one course per semester at the least
+    pub path: Vec<CourseCode>,
+}
+
+/// A major without a semester template, or a minor or certificate with one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KindMismatch {
+    pub program: String,
+    pub kind: ProgramKind,
+}
+
+impl Catalog {
+    /// Check the catalog for dangling course codes, prerequisite cycles, courses that can never
+    /// be taken, program templates that break their own prerequisites, and programs whose
+    /// template doesn't fit their kind.
+    pub fn lint(&self) -> LintReport {
+        LintReport {
+            dangling_codes: self.dangling_codes(),
+            prereq_cycles: PrereqGraph::new(self).cycles(),
+            unsatisfiable_courses: self.unsatisfiable_courses(),
+            template_violations: self.template_violations(),
+            short_templates: self.short_templates(),
+            kind_mismatches: self.kind_mismatches(),
+        }
+    }
+
+    fn kind_mismatches(&self) -> Vec<KindMismatch> {
+        self.programs
+            .iter()
+            .filter(|p| (p.kind == ProgramKind::Major) == p.semesters.is_empty())
+            .map(|p| KindMismatch {
+                program: p.name.clone(),
+                kind: p.kind,
+            })
+            .collect()
+    }
+
+    fn dangling_codes(&self) -> Vec<DanglingCode> {
+        let mut refs: Vec<(String, Vec<CourseCode>)> = vec![];
+        for program in &self.programs {
+            let mut codes: Vec<CourseCode> = program.required_courses().cloned().collect();
+            codes.extend(program.electives.iter().flat_map(|e| e.all_course_codes()));
+            refs.push((program.name.clone(), codes));
+        }
+        for (code, req) in &self.prereqs {
+            refs.push(("the prerequisite list".to_string(), vec![code.clone()]));
+            refs.push((format!("prerequisites of {}", code), req.all_course_codes()));
+        }
+        for gened in &self.geneds {
+            let codes = gened.req().course_codes().into_iter().cloned().collect();
+            refs.push((format!("gened {}", gened.name()), codes));
+        }
+        for equivalency in &self.equivalencies {
+            refs.push((
+                format!("equivalency {}", equivalency.name),
+                equivalency.equivalents.clone(),
+            ));
+        }
+
+        let mut dangling: Vec<DanglingCode> = vec![];
+        for (referenced_by, codes) in refs {
+            let mut seen = HashSet::new();
+            for code in codes {
+                if !self.courses.contains_key(&code) && seen.insert(code.clone()) {
+                    dangling.push(DanglingCode {
+                        code,
+                        referenced_by: referenced_by.clone(),
+                    });
+                }
+            }
+        }
+        dangling.sort_by_key(|d| (d.code.to_string(), d.referenced_by.clone()));
+        dangling
+    }
+
+    fn unsatisfiable_courses(&self) -> Vec<CourseCode> {
+        // Walk every term, Summer and Winter included, recording the first term each course
+        // could be taken in. Offerings repeat every two years, so once two full years pass
+        // without a new course becoming available, none ever will.
+        let policy = CreditPolicy {
+            summer: Some(CreditLimits::default()),
+            winter: Some(CreditLimits::default()),
+            ..Default::default()
+        };
+        let codes: HashSet<&CourseCode> = self.courses.keys().chain(self.prereqs.keys()).collect();
+        let mut earliest: HashMap<&CourseCode, usize> = HashMap::new();
+        let mut term = Term::new(Season::Fall, self.low_year);
+        let mut idx = 0;
+        let mut last_new = 0;
+        while idx - last_new < 8 {
+            // Co-requisites may be taken together, so start from every course offered this term
+            // and drop those whose prerequisites aren't met until none are left to drop
+            let mut candidates: HashSet<&CourseCode> = codes
+                .iter()
+                .filter(|code| {
+                    !earliest.contains_key(*code)
+                        && term.offers(self.courses.get(**code).map(|(_, _, o)| o))
+                })
+                .copied()
+                .collect();
+            loop {
+                let taken = |c: &CourseCode, concurrent: bool| {
+                    earliest.contains_key(c)
+                        || (concurrent && candidates.contains(c))
+                        // Dangling codes are reported on their own
+                        || !codes.contains(c)
+                };
+                let unmet: Vec<&CourseCode> = candidates
+                    .iter()
+                    .filter(|code| {
+                        !self
+                            .prereqs
+                            .get(**code)
+                            .is_none_or(|req| req.is_met_by(&taken))
+                    })
+                    .copied()
+                    .collect();
+                if unmet.is_empty() {
+                    break;
+                }
+                for code in unmet {
+                    candidates.remove(code);
+                }
+            }
+            if !candidates.is_empty() {
+                last_new = idx;
+            }
+            earliest.extend(candidates.into_iter().map(|code| (code, idx)));
+            term = term.next(&policy);
+            idx += 1;
+        }
+        let mut unsatisfiable: Vec<CourseCode> = codes
+            .into_iter()
+            .filter(|code| !earliest.contains_key(code))
+            .cloned()
+            .collect();
+        unsatisfiable.sort_by_key(|c| c.to_string());
+        unsatisfiable
+    }
+
+    fn short_templates(&self) -> Vec<ShortTemplate> {
+        let graph = PrereqGraph::new(self);
+        let sems = graph.min_semesters(&HashSet::new());
+        let mut short = vec![];
+        for (name, path) in graph.program_critical_paths() {
+            let Some(program) = self.programs.iter().find(|p| p.name == name) else {
+                continue;
+            };
+            let needed = path.last().map_or(0, |capstone| sems[capstone]);
+            // Minors and certificates have no template to fall short
+            if !program.semesters.is_empty() && program.semesters.len() < needed {
+                short.push(ShortTemplate {
+                    program: name,
+                    semesters: program.semesters.len(),
+                    path: path.into_iter().cloned().collect(),
+                });
+            }
+        }
+        short
+    }
+
+    fn template_violations(&self) -> Vec<TemplateViolation> {
+        let mut violations = vec![];
+        for program in &self.programs {
+            let mut sem_of: HashMap<&CourseCode, usize> = HashMap::new();
+            for (idx, sem) in program.semesters.iter().enumerate() {
+                for code in sem {
+                    sem_of.entry(code).or_insert(idx);
+                }
+            }
+            for (idx, sem) in program.semesters.iter().enumerate() {
+                for code in sem {
+                    let Some(req) = self.prereqs.get(code) else {
+                        continue;
+                    };
+                    // Courses outside the template may be taken whenever the student likes
+                    let taken = |c: &CourseCode, concurrent: bool| match sem_of.get(c) {
+                        Some(&s) => s < idx || (concurrent && s == idx),
+                        None => true,
+                    };
+                    if !req.is_met_by(&taken) {
+                        violations.push(TemplateViolation {
+                            program: program.name.clone(),
+                            course: code.clone(),
+                            semester: idx,
+                            req: req.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        violations
+    }
+}
+
+impl LintReport {
+    pub fn is_clean(&self) -> bool {
+        self.dangling_codes.is_empty()
+            && self.prereq_cycles.is_empty()
+            && self.unsatisfiable_courses.is_empty()
+            && self.template_violations.is_empty()
+            && self.short_templates.is_empty()
+            && self.kind_mismatches.is_empty()
+    }
+
+    /// One line per problem, in a stable order.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for dangling in &self.dangling_codes {
+            lines.push(dangling.to_string());
+        }
+        for cycle in &self.prereq_cycles {
+            let names: Vec<String> = cycle.iter().map(|c| c.to_string()).collect();
+            lines.push(format!(
+                "prerequisite cycle: {} -> {}",
+                names.join(" -> "),
+                names[0]
+            ));
+        }
+        for code in &self.unsatisfiable_courses {
+            lines.push(format!("{}: prerequisites can never be satisfied", code));
+        }
+        for violation in &self.template_violations {
+            lines.push(violation.to_string());
+        }
+        for short in &self.short_templates {
+            lines.push(short.to_string());
+        }
+        for mismatch in &self.kind_mismatches {
+            lines.push(mismatch.to_string());
+        }
+        lines
+    }
+}
+
+impl fmt::Display for LintReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_clean() {
+            return write!(f, "Catalog is clean");
+        }
+        write!(f, "Catalog has problems:")?;
+        for line in self.lines() {
+            write!(f, "\n  - {}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DanglingCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: referenced by {} but not in the catalog",
+            self.code, self.referenced_by
+        )
+    }
+}
+
+impl fmt::Display for TemplateViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} in semester {} comes before its prerequisites ({})",
+            self.program,
+            self.course,
+            self.semester + 1,
+            self.req
+        )
+    }
+}
+
+impl fmt::Display for ShortTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path: Vec<String> = self.path.iter().map(|c| c.to_string()).collect();
+        write!(
+            f,
+            "{}: {} semesters is too few for the prerequisite chain {}",
+            self.program,
+            self.semesters,
+            path.join(" -> ")
+        )
+    }
+}
+
+impl fmt::Display for KindMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ProgramKind::Major => write!(f, "{}: a major needs a semester template", self.program),
+            kind => write!(
+                f,
+                "{}: a {} can't have a semester template",
+                self.program,
+                format!("{:?}", kind).to_lowercase()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::tests::{catalog, code};
+    use serde_json::json;
+
+    #[test]
+    fn clean_catalog_has_no_problems() {
+        let report = catalog(json!({
+            "programs": [{"name": "BS Physics", "kind": "Major",
+                          "semesters": [["MATH-1300"], ["PHYS-2100", "PHYS-2101"]]}],
+            "prereqs": {
+                "PHYS-2100": {"And": [{"PreCourse": "MATH-1300"}, {"CoCourse": "PHYS-2101"}]},
+                "PHYS-2101": {"CoCourse": "PHYS-2100"}
+            },
+            "courses": {
+                "MATH-1300": ["Calculus I", 4, "Both"],
+                "PHYS-2100": ["University Physics I", 4, "Spring"],
+                "PHYS-2101": ["University Physics I Lab", 1, "Spring"]
+            }
+        }))
+        .lint();
+        assert_eq!(report, LintReport::default());
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn dangling_codes_are_reported_once_per_reference() {
+        let report = catalog(json!({
+            "programs": [{"name": "BS Physics", "kind": "Major",
+                          "semesters": [["PHYS-2100"], ["PHYS-2100"]]}],
+            "prereqs": {"PHYS-2100": {"PreCourse": "MATH-1300"}},
+            "courses": {"MATH-1300": ["Calculus I", 4, "Both"]}
+        }))
+        .lint();
+        assert_eq!(
+            report.lines(),
+            [
+                "PHYS-2100: referenced by BS Physics but not in the catalog",
+                "PHYS-2100: referenced by the prerequisite list but not in the catalog",
+            ]
+        );
+    }
+
+    #[test]
+    fn paired_co_requisites_offered_in_different_terms_are_unsatisfiable() {
+        let report = catalog(json!({
+            "prereqs": {
+                "CHEM-1200": {"CoCourse": "CHEM-1201"},
+                "CHEM-1201": {"CoCourse": "CHEM-1200"}
+            },
+            "courses": {
+                "CHEM-1200": ["General Chemistry I", 4, "Fall"],
+                "CHEM-1201": ["General Chemistry I Lab", 1, "Spring"],
+                "CHEM-2100": ["Organic Chemistry I", 4, "FallEven"]
+            }
+        }))
+        .lint();
+        assert_eq!(
+            report.unsatisfiable_courses,
+            vec![code("CHEM-1200"), code("CHEM-1201")]
+        );
+        // A co-requisite pair is not a cycle
+        assert!(report.prereq_cycles.is_empty());
+    }
+
+    #[test]
+    fn templates_may_not_place_a_course_before_its_prerequisites() {
+        let report = catalog(json!({
+            "programs": [{"name": "BS Physics", "kind": "Major",
+                          "semesters": [["PHYS-2100", "PHYS-2101"], ["MATH-1300"]]}],
+            "prereqs": {
+                "PHYS-2100": {"PreCourse": "MATH-1300"},
+                "PHYS-2101": {"CoCourse": "PHYS-2100"}
+            },
+            "courses": {
+                "MATH-1300": ["Calculus I", 4, "Both"],
+                "PHYS-2100": ["University Physics I", 4, "Both"],
+                "PHYS-2101": ["University Physics I Lab", 1, "Both"]
+            }
+        }))
+        .lint();
+        assert_eq!(
+            report.template_violations,
+            vec![TemplateViolation {
+                program: "BS Physics".to_string(),
+                course: code("PHYS-2100"),
+                semester: 0,
+                req: CourseReq::PreCourse(code("MATH-1300")),
+            }]
+        );
+    }
+
+    #[test]
+    fn templates_must_match_the_program_kind() {
+        let report = catalog(json!({
+            "programs": [
+                {"name": "BS Physics", "kind": "Major", "required": ["PHYS-2100"]},
+                {"name": "Physics Minor", "kind": "Minor", "semesters": [["PHYS-2100"]]},
+                {"name": "Astronomy Certificate", "kind": "Certificate", "required": ["PHYS-2100"]}
+            ],
+            "courses": {"PHYS-2100": ["University Physics I", 4, "Both"]}
+        }))
+        .lint();
+        assert_eq!(
+            report.lines(),
+            [
+                "BS Physics: a major needs a semester template",
+                "Physics Minor: a minor can't have a semester template",
+            ]
+        );
+    }
+}
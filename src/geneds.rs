@@ -1,8 +1,9 @@
-use crate::schedule::{Catalog, CourseCode, Schedule};
-use anyhow::Result;
+use crate::model::assign_geneds;
+use crate::schedule::{CourseCode, Schedule};
+use crate::validation::{OverlapViolation, Shortfall, UnmetGenEd, ValidationReport};
 use savefile_derive::Savefile;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 #[derive(Clone, Debug, Savefile, Serialize, Deserialize, Hash, Eq, PartialEq)]
 pub enum GenEd {
@@ -11,6 +12,59 @@ pub enum GenEd {
     SkillAndPerspective { name: String, req: GenEdReq },
 }
 
+impl GenEd {
+    pub fn name(&self) -> &str {
+        match self {
+            GenEd::Core { name, .. }
+            | GenEd::Foundation { name, .. }
+            | GenEd::SkillAndPerspective { name, .. } => name,
+        }
+    }
+
+    pub fn req(&self) -> &GenEdReq {
+        match self {
+            GenEd::Core { req, .. }
+            | GenEd::Foundation { req, .. }
+            | GenEd::SkillAndPerspective { req, .. } => req,
+        }
+    }
+}
+
+/// How geneds may share courses, which differs between catalog years and institutions.
+#[derive(Clone, Debug, Savefile, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GenEdOverlapPolicy {
+    /// Most Core geneds one course may count toward; `None` for no limit
+    pub max_core: Option<usize>,
+    /// Most Foundations one course may count toward; `None` for no limit
+    pub max_foundations: Option<usize>,
+    /// Most Skills & Perspectives one course may count toward; `None` for no limit
+    pub max_skills_and_perspectives: Option<usize>,
+    /// Foundations, by name, that may count the same course; no other two Foundations may share one
+    pub allowed_foundation_pairs: Vec<(String, String)>,
+}
+
+impl GenEdOverlapPolicy {
+    /// Most geneds of the same kind as `gened` that one course may count toward.
+    pub fn max_for(&self, gened: &GenEd) -> Option<usize> {
+        match gened {
+            GenEd::Core { .. } => self.max_core,
+            GenEd::Foundation { .. } => self.max_foundations,
+            GenEd::SkillAndPerspective { .. } => self.max_skills_and_perspectives,
+        }
+    }
+
+    /// Whether one course may count toward both `a` and `b`.
+    pub fn may_share(&self, a: &GenEd, b: &GenEd) -> bool {
+        match (a, b) {
+            (GenEd::Foundation { name: a, .. }, GenEd::Foundation { name: b, .. }) => self
+                .allowed_foundation_pairs
+                .iter()
+                .any(|(x, y)| (x == a && y == b) || (x == b && y == a)),
+            _ => true,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Savefile, Serialize, Deserialize, Hash, Eq, PartialEq)]
 pub enum GenEdReq {
     Set(Vec<CourseCode>),
@@ -23,234 +77,211 @@ pub enum GenEdReq {
         num: u32,
         courses: Vec<CourseCode>,
     },
+    /// The inner requirement, met with courses from at least `num` different stems
+    DistinctStems {
+        num: usize,
+        req: Box<GenEdReq>,
+    },
+    /// The inner requirement, met with at least one of `labs` among its courses
+    IncludesLab {
+        labs: Vec<CourseCode>,
+        req: Box<GenEdReq>,
+    },
+}
+
+impl GenEdReq {
+    /// Every course that can count toward this requirement.
+    pub fn course_codes(&self) -> Vec<&CourseCode> {
+        match self {
+            GenEdReq::Set(codes)
+            | GenEdReq::Courses { courses: codes, .. }
+            | GenEdReq::Credits { courses: codes, .. } => codes.iter().collect(),
+            GenEdReq::SetOpts(opts) => opts.iter().flatten().collect(),
+            GenEdReq::DistinctStems { req, .. } => req.course_codes(),
+            GenEdReq::IncludesLab { labs, req } => {
+                let mut codes = req.course_codes();
+                codes.extend(
+                    labs.iter()
+                        .filter(|lab| !codes.contains(lab))
+                        .collect::<Vec<_>>(),
+                );
+                codes
+            }
+        }
+    }
 }
 
-// Helper: for a GenEdReq, return a set of courses from schedule that can be used to satisfy it, or None if not possible
-fn satisfy_req<'a>(
-    req: &'a GenEdReq,
-    sched_courses: &HashSet<&'a CourseCode>,
-    catalog: &Catalog,
-) -> Option<HashSet<&'a CourseCode>> {
+// Helper: what a GenEdReq still needs beyond the courses in the schedule, or None if it is met
+fn shortfall(
+    req: &GenEdReq,
+    sched_courses: &HashSet<&CourseCode>,
+    sched: &Schedule,
+) -> Option<Shortfall> {
+    let missing = |codes: &[CourseCode]| -> Vec<CourseCode> {
+        codes
+            .iter()
+            .filter(|c| !sched_courses.contains(c))
+            .cloned()
+            .collect()
+    };
     match req {
         GenEdReq::Set(codes) => {
-            let set: HashSet<_> = codes
-                .iter()
-                .filter_map(|c| sched_courses.get(c))
-                .copied()
-                .collect();
-            if set.len() == codes.len() {
-                Some(set)
-            } else {
-                None
-            }
+            let missing = missing(codes);
+            (!missing.is_empty()).then_some(Shortfall::AllOf(missing))
         }
         GenEdReq::SetOpts(opts) => {
-            for opt in opts {
-                let set: HashSet<_> = opt
-                    .iter()
-                    .filter_map(|c| sched_courses.get(c))
-                    .copied()
-                    .collect();
-                if set.len() == opt.len() {
-                    return Some(set);
-                }
-            }
-            None
+            let closest = opts
+                .iter()
+                .map(|opt| missing(opt))
+                .min_by_key(|m| m.len())
+                .unwrap_or_default();
+            (!closest.is_empty()).then_some(Shortfall::AllOf(closest))
         }
         GenEdReq::Courses { num, courses } => {
-            let available: Vec<_> = courses
-                .iter()
-                .filter_map(|c| sched_courses.get(c))
-                .copied()
-                .collect();
-            if available.len() >= *num {
-                Some(available.into_iter().take(*num).collect())
-            } else {
-                None
-            }
+            let have = courses.iter().filter(|c| sched_courses.contains(c)).count();
+            (have < *num).then(|| Shortfall::Courses {
+                num: num - have,
+                from: missing(courses),
+            })
         }
         GenEdReq::Credits { num, courses } => {
-            let mut available: Vec<_> = courses
+            let have: u32 = courses
+                .iter()
+                .filter(|c| sched_courses.contains(c))
+                .map(|c| sched.credits(c))
+                .sum();
+            (have < *num).then(|| Shortfall::Credits {
+                num: num - have,
+                from: missing(courses),
+            })
+        }
+        GenEdReq::DistinctStems { num, req } => {
+            if let Some(inner) = shortfall(req, sched_courses, sched) {
+                return Some(inner);
+            }
+            let codes = req.course_codes();
+            let stems: HashSet<&str> = codes
                 .iter()
-                .filter_map(|c| sched_courses.get(c))
-                .copied()
+                .filter(|c| sched_courses.contains(*c))
+                .map(|c| c.stem.as_str())
                 .collect();
-            // Sort by credits descending
-            available.sort_by_key(|c| {
-                -(catalog
-                    .courses
-                    .get(*c)
-                    .and_then(|(_, cr, _)| *cr)
-                    .unwrap_or(0) as i32)
-            });
-            let mut selected = HashSet::new();
-            let mut total = 0u32;
-            for c in available {
-                let cr = catalog
-                    .courses
-                    .get(c)
-                    .and_then(|(_, cr, _)| *cr)
-                    .unwrap_or(0) as u32;
-                selected.insert(c);
-                total += cr;
-                if total >= *num {
-                    break;
-                }
+            (stems.len() < *num).then(|| Shortfall::Stems {
+                num: num - stems.len(),
+                from: codes
+                    .into_iter()
+                    .filter(|c| !stems.contains(c.stem.as_str()))
+                    .cloned()
+                    .collect(),
+            })
+        }
+        GenEdReq::IncludesLab { labs, req } => {
+            if let Some(inner) = shortfall(req, sched_courses, sched) {
+                return Some(inner);
             }
-            if total >= *num { Some(selected) } else { None }
+            (!labs.iter().any(|lab| sched_courses.contains(lab))).then(|| Shortfall::Courses {
+                num: 1,
+                from: missing(labs),
+            })
         }
     }
 }
 
-pub fn are_geneds_satisfied(sched: &Schedule) -> Result<bool> {
-    let sched_courses: HashSet<&CourseCode> = sched.courses.iter().flatten().collect();
-    // 1. Core: each must be satisfied, overlap allowed
-    let mut all_core_ok = true;
-    for gened in sched.catalog.geneds.iter() {
-        if let GenEd::Core { req, name } = gened {
-            if satisfy_req(req, &sched_courses, &sched.catalog).is_none() {
-                all_core_ok = false;
-            }
+/// Record every unmet gened and overlap violation in `report`.
+///
+/// Each gened is first checked on its own. Those that are met are then assigned courses together with the
+/// scheduler's own formulation, so a schedule the solver produces always validates.
+pub fn validate_geneds(sched: &Schedule, report: &mut ValidationReport) {
+    let sched_courses: HashSet<&CourseCode> = sched.taken_courses();
+    let mut met = Vec::new();
+    for gened in &sched.catalog.geneds {
+        match shortfall(gened.req(), &sched_courses, sched) {
+            Some(missing) => report.unmet_geneds.push(UnmetGenEd {
+                name: gened.name().to_string(),
+                missing,
+            }),
+            None => met.push(gened),
         }
     }
-    if !all_core_ok {
-        return Ok(false);
-    }
 
-    // 2. Foundation: each must be satisfied, but no course can be used for more than one Foundation
-    let mut foundation_reqs = vec![];
-    let mut foundation_names = vec![];
-    for gened in sched.catalog.geneds.iter() {
-        if let GenEd::Foundation { req, name } = gened {
-            foundation_reqs.push(req);
-            foundation_names.push(name);
-            // Print eligible courses for this Foundation
-            let eligible: Vec<_> = match req {
-                GenEdReq::Set(codes) => codes.clone(),
-                GenEdReq::SetOpts(opts) => opts.iter().flatten().cloned().collect(),
-                GenEdReq::Courses { courses, .. } => courses.clone(),
-                GenEdReq::Credits { courses, .. } => courses.clone(),
-            };
-            // Print which eligible courses are present in the schedule
-            let sched_courses: HashSet<&CourseCode> = sched.courses.iter().flatten().collect();
-            let present: Vec<_> = eligible
-                .iter()
-                .filter(|c| sched_courses.contains(c))
-                .collect();
-        }
+    let mut taken: Vec<&CourseCode> = sched_courses.into_iter().collect();
+    taken.sort_by_key(|c| c.to_string());
+    if assign_geneds(&met, &taken, sched).is_some() {
+        return;
     }
-    // Try all possible assignments of courses to foundation geneds (backtracking)
-    fn assign_foundations<'a>(
-        reqs: &[&'a GenEdReq],
-        names: &[&'a String],
-        idx: usize,
-        used: &mut HashSet<&'a CourseCode>,
-        sched_courses: &HashSet<&'a CourseCode>,
-        catalog: &Catalog,
-        fail_foundation: &mut Option<String>,
-    ) -> bool {
-        if idx == reqs.len() {
-            return true;
-        }
-        if let Some(candidates) = satisfy_req(reqs[idx], sched_courses, catalog) {
-            let available: Vec<_> = candidates.difference(used).copied().collect();
-            if available.len() < candidates.len() {
-                *fail_foundation = Some(names[idx].clone());
-                return false;
-            }
-            // Try all combinations of candidates from available (order doesn't matter)
-            fn combinations<'b, T: Clone>(
-                data: &'b [T],
-                k: usize,
-                out: &mut Vec<T>,
-                res: &mut Vec<Vec<T>>,
-            ) {
-                if out.len() == k {
-                    res.push(out.clone());
-                    return;
+    // Keep each gened that can be met alongside the ones kept before it; the rest are blocked by the
+    // catalog's overlap policy
+    let mut kept = Vec::new();
+    for gened in met {
+        kept.push(gened);
+        if assign_geneds(&kept, &taken, sched).is_none() {
+            kept.pop();
+            report.overlap_violations.push(match gened {
+                GenEd::Core { name, .. } => OverlapViolation::Core { name: name.clone() },
+                GenEd::Foundation { name, .. } => {
+                    OverlapViolation::Foundation { name: name.clone() }
                 }
-                for i in 0..data.len() {
-                    out.push(data[i].clone());
-                    let rest = &data[i + 1..];
-                    combinations(rest, k, out, res);
-                    out.pop();
+                GenEd::SkillAndPerspective { name, .. } => {
+                    OverlapViolation::SkillAndPerspective { name: name.clone() }
                 }
-            }
-            let mut combs = Vec::new();
-            let mut out = Vec::new();
-            combinations(&available, candidates.len(), &mut out, &mut combs);
-            for comb in &combs {
-                let mut new_used = used.clone();
-                new_used.extend(comb.iter().copied());
-                if assign_foundations(
-                    reqs,
-                    names,
-                    idx + 1,
-                    &mut new_used,
-                    sched_courses,
-                    catalog,
-                    fail_foundation,
-                ) {
-                    return true;
-                }
-            }
-            *fail_foundation = Some(names[idx].clone());
-            false
-        } else {
-            *fail_foundation = Some(names[idx].clone());
-            false
-        }
-    }
-    if !foundation_reqs.is_empty() {
-        let mut used = HashSet::new();
-        let mut fail_foundation = None;
-        let req_refs: Vec<&GenEdReq> = foundation_reqs.iter().map(|r| *r).collect();
-        let name_refs: Vec<&String> = foundation_names.iter().map(|r| *r).collect();
-        if !assign_foundations(
-            &req_refs,
-            &name_refs,
-            0,
-            &mut used,
-            &sched_courses,
-            &sched.catalog,
-            &mut fail_foundation,
-        ) {
-            return Ok(false);
+            });
         }
     }
+}
 
-    // 3. Skills & Perspectives: each must be satisfied, but no course can be used for more than 3 S&Ps
-    let mut sp_reqs = vec![];
-    let mut sp_names = vec![];
-    for gened in sched.catalog.geneds.iter() {
-        if let GenEd::SkillAndPerspective { req, name } = gened {
-            sp_reqs.push(req);
-            sp_names.push(name);
-        }
-    }
-    let mut all_sp_ok = true;
-    let mut sp_course_counts: HashMap<&CourseCode, usize> = HashMap::new();
-    for (i, req) in sp_reqs.iter().enumerate() {
-        if let Some(courses) = satisfy_req(req, &sched_courses, &sched.catalog) {
-            for c in courses {
-                *sp_course_counts.entry(c).or_insert(0) += 1;
-            }
-        } else {
-            all_sp_ok = false;
-        }
-    }
-    if !all_sp_ok {
-        return Ok(false);
-    }
-    // No course can be used for more than 3 S&Ps
-    let mut sp_overlap_fail = false;
-    for (c, count) in &sp_course_counts {
-        if *count > 3 {
-            sp_overlap_fail = true;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::tests::{catalog, code, schedule};
+    use serde_json::json;
+
+    #[test]
+    fn credit_geneds_count_the_chosen_amount() {
+        let catalog = catalog(json!({
+            "geneds": [{"Core": {"name": "Research", "req": {"Credits": {"courses": ["PHYS-4900"], "num": 3}}}}],
+            "courses": {"PHYS-4900": ["Research", [1, 3], "Both"]}
+        }));
+        let mut sched = schedule(catalog, &[], vec![vec![code("PHYS-4900")]]);
+        let mut report = ValidationReport::default();
+        validate_geneds(&sched, &mut report);
+        assert_eq!(
+            report.unmet_geneds,
+            vec![UnmetGenEd {
+                name: "Research".to_string(),
+                missing: Shortfall::Credits {
+                    num: 2,
+                    from: vec![],
+                },
+            }]
+        );
+
+        sched.chosen_credits.insert(code("PHYS-4900"), 3);
+        let mut report = ValidationReport::default();
+        validate_geneds(&sched, &mut report);
+        assert!(report.unmet_geneds.is_empty());
     }
-    if sp_overlap_fail {
-        return Ok(false);
+
+    #[test]
+    fn overlap_policy_limits_sharing_by_kind_and_pair() {
+        let policy = GenEdOverlapPolicy {
+            max_core: None,
+            max_foundations: Some(2),
+            max_skills_and_perspectives: Some(3),
+            allowed_foundation_pairs: vec![("Writing".to_string(), "Literature".to_string())],
+        };
+        let req = GenEdReq::Set(vec![]);
+        let foundation = |name: &str| GenEd::Foundation {
+            name: name.to_string(),
+            req: req.clone(),
+        };
+        let core = GenEd::Core {
+            name: "Theology".to_string(),
+            req: req.clone(),
+        };
+        assert_eq!(policy.max_for(&core), None);
+        assert_eq!(policy.max_for(&foundation("Writing")), Some(2));
+        // Allowed pairs work in either order; other Foundations may not share
+        assert!(policy.may_share(&foundation("Literature"), &foundation("Writing")));
+        assert!(!policy.may_share(&foundation("Writing"), &foundation("Quantitative")));
+        assert!(policy.may_share(&core, &foundation("Quantitative")));
     }
-    Ok(true)
 }
@@ -1,25 +1,78 @@
+use anyhow::{Context, Result, bail};
 use lazy_static::lazy_static;
+use std::path::Path;
 
-#[path = "../resources/course_reqs/mod.rs"]
-mod course_reqs;
+use crate::schedule::Catalog;
 
-#[path = "../resources/programs/mod.rs"]
-mod programs;
+/// Directory catalogs are read from unless `ROSS_CATALOG_DIR` says otherwise.
+pub const DEFAULT_CATALOG_DIR: &str = "catalogs";
 
-#[path = "../resources/courses.rs"]
-mod courses;
+lazy_static! {
+    /// Copies of the catalog files compiled into the binary, used when no catalog directory is
+    /// present.
+    pub static ref BUILTIN_CATALOGS: Vec<Catalog> = vec![
+        serde_json::from_str(include_str!("../catalogs/2025-2026.json"))
+            .expect("Built-in 2025-2026 catalog is invalid"),
+    ];
+}
 
-#[path = "../resources/general_education.rs"]
-mod general_education;
+/// Load every catalog from the catalog directory (`ROSS_CATALOG_DIR`, or `catalogs`), falling
+/// back to the built-in catalogs if the directory doesn't exist.
+pub fn load_catalogs() -> Result<Vec<Catalog>> {
+    let dir = std::env::var("ROSS_CATALOG_DIR").unwrap_or_else(|_| DEFAULT_CATALOG_DIR.to_string());
+    let dir = Path::new(&dir);
+    if !dir.is_dir() {
+        return Ok(BUILTIN_CATALOGS.clone());
+    }
+    load_catalog_dir(dir)
+}
 
-use crate::schedule::Catalog;
+/// Load every `*.json` catalog in `dir`, oldest catalog year first.
+pub fn load_catalog_dir(dir: &Path) -> Result<Vec<Catalog>> {
+    let mut catalogs = Vec::new();
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read catalog directory {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            catalogs.push(load_catalog_file(&path)?);
+        }
+    }
+    if catalogs.is_empty() {
+        bail!("No catalogs found in {}", dir.display());
+    }
+    catalogs.sort_by_key(|c| c.low_year);
+    Ok(catalogs)
+}
 
-lazy_static! {
-    pub static ref CATALOGS: Vec<Catalog> = vec![Catalog {
-        geneds: general_education::geneds(),
-        programs: programs::programs(),
-        prereqs: course_reqs::prereqs(),
-        courses: courses::courses(),
-        low_year: 2025,
-    }];
+/// The catalog of record for students who entered under the `low_year` catalog.
+pub fn catalog_of_record(catalogs: &[Catalog], low_year: u32) -> Result<&Catalog> {
+    catalogs
+        .iter()
+        .find(|c| c.low_year == low_year)
+        .with_context(|| format!("No {}-{} catalog found", low_year, low_year + 1))
+}
+
+/// Load a single catalog file.
+pub fn load_catalog_file(path: &Path) -> Result<Catalog> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Failed to open catalog {}", path.display()))?;
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("Failed to parse catalog {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_catalogs_match_the_catalog_directory() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_CATALOG_DIR);
+        let from_dir = load_catalog_dir(&dir).unwrap();
+        let to_json = |c: &Catalog| serde_json::to_value(c).unwrap();
+        assert_eq!(
+            BUILTIN_CATALOGS.iter().map(to_json).collect::<Vec<_>>(),
+            from_dir.iter().map(to_json).collect::<Vec<_>>()
+        );
+    }
 }
@@ -1,55 +1,149 @@
 use anyhow::{Result, anyhow};
 use std::path::Path;
 
+mod catalog_diff;
+mod catalog_lint;
 mod geneds;
 mod load_catalogs;
 mod model;
+mod prereq_graph;
 mod prereqs;
+mod prior_credit;
 mod read_excel_file;
 mod schedule;
+mod terms;
+mod validation;
 mod version;
 mod write_excel_file;
 
-use load_catalogs::CATALOGS;
-use schedule::generate_schedule;
+use load_catalogs::{catalog_of_record, load_catalogs};
+use prior_credit::{PriorCredit, PriorCreditSource};
+use schedule::{Catalog, ScheduleOptions, generate_schedule};
+use terms::{Season, Term};
 pub use version::{SAVEFILE_VERSION, VERSION};
 use write_excel_file::save_schedule;
 
 pub static TEMPLATE_PNG: &[u8] = include_bytes!("../assets/template.png");
-pub const MAX_CREDITS_PER_SEMESTER: i64 = 18;
 
 fn main() -> Result<()> {
     const FNAME: &str = "ross_test.xlsx";
 
-    let sched = generate_schedule(
-        CATALOGS
-            .first()
-            .ok_or(anyhow!("no catalogs found"))?
+    let catalogs = load_catalogs()?;
+    if std::env::args().nth(1).as_deref() == Some("lint") {
+        return lint_catalogs(&catalogs);
+    }
+    // Programs are named on the command line (e.g. a double major); default to the first one.
+    // `--catalog YEAR` picks the catalog of record (default: the newest), and `--replan YEAR`
+    // re-plans the schedule under a newer catalog. `--start SEASON YEAR` sets the first planned
+    // term (default: Fall of the catalog year). `--ap`, `--dual` and `--transfer` add prior
+    // credit as `NAME` or `NAME=CREDITS`.
+    let mut args: Vec<String> = vec![];
+    let mut catalog_year = None;
+    let mut replan_year = None;
+    let mut start = None;
+    let mut prior_specs: Vec<(PriorCreditSource, String)> = vec![];
+    let mut cli = std::env::args().skip(1);
+    while let Some(arg) = cli.next() {
+        match arg.as_str() {
+            "--catalog" | "--replan" => {
+                let year: u32 = cli
+                    .next()
+                    .ok_or(anyhow!("{} needs a catalog year", arg))?
+                    .parse()?;
+                if arg == "--catalog" {
+                    catalog_year = Some(year);
+                } else {
+                    replan_year = Some(year);
+                }
+            }
+            "--start" => {
+                let season: Season = cli
+                    .next()
+                    .ok_or(anyhow!("--start needs a season and year"))?
+                    .parse()?;
+                let year: u32 = cli
+                    .next()
+                    .ok_or(anyhow!("--start needs a season and year"))?
+                    .parse()?;
+                start = Some(Term::new(season, year));
+            }
+            "--ap" | "--dual" | "--transfer" => {
+                let source = match arg.as_str() {
+                    "--ap" => PriorCreditSource::AdvancedPlacement,
+                    "--dual" => PriorCreditSource::DualEnrollment,
+                    _ => PriorCreditSource::Transfer,
+                };
+                let spec = cli.next().ok_or(anyhow!("{} needs a course name", arg))?;
+                prior_specs.push((source, spec));
+            }
+            _ => args.push(arg),
+        }
+    }
+    let catalog = match catalog_year {
+        Some(year) => catalog_of_record(&catalogs, year)?,
+        None => catalogs.last().ok_or(anyhow!("no catalogs found"))?,
+    };
+    let programs: Vec<&str> = if args.is_empty() {
+        catalog
             .programs
             .iter()
             .map(|x| x.name.as_str())
             .take(1)
-            .collect(),
-        CATALOGS
-            .first()
-            .ok_or(anyhow!("no catalogs found"))?
-            .clone(),
-    )?;
+            .collect()
+    } else {
+        args.iter().map(String::as_str).collect()
+    };
+    let start = start.unwrap_or(Term::new(Season::Fall, catalog.low_year));
+    let mut options = ScheduleOptions::starting(start);
+    for (source, spec) in &prior_specs {
+        options
+            .prior_credit
+            .push(parse_prior_credit(*source, spec, catalog)?);
+    }
+    let sched = generate_schedule(programs, catalog.clone(), options)?;
+    let sched = match replan_year {
+        Some(year) => {
+            let (replanned, diff) =
+                sched.replan_under(catalog_of_record(&catalogs, year)?.clone())?;
+            println!("{}", diff);
+            replanned
+        }
+        None => sched,
+    };
 
     println!("Final schedule (two-stage, balanced):");
+    let consent_courses = sched.consent_dependent_courses();
+    let at_risk_courses = sched.at_risk_courses();
     let mut sched_credits = 0;
+    if !sched.prior_credit.is_empty() {
+        println!("Prior credit");
+        for credit in &sched.prior_credit {
+            println!("  {} ({} credits)", credit, credit.credits);
+            sched_credits += credit.credits;
+        }
+    }
     for (s, semester) in sched.courses.iter().enumerate() {
-        println!("Semester {}", s + 1);
+        println!("{}", sched.terms[s]);
         let mut sem_credits = 0;
         for code in semester {
-            // Look up credits from catalog
-            let credits = sched
-                .catalog
-                .courses
-                .get(code)
-                .and_then(|(_, cr, _)| *cr)
-                .unwrap_or(0);
-            println!("  {} ({} credits)", code, credits);
+            // Chosen credits for variable-credit courses, otherwise the catalog's
+            let credits = sched.credits(code);
+            let mut flags = String::new();
+            if sched.programs.len() > 1
+                && let Some(programs) = sched.program_assignments.get(code)
+            {
+                flags.push_str(&format!(", for {}", programs.join(", ")));
+            }
+            if let Some(geneds) = sched.gened_assignments.get(code) {
+                flags.push_str(&format!(", counts toward {}", geneds.join(", ")));
+            }
+            if consent_courses.contains(code) {
+                flags.push_str(", assumes instructor consent");
+            }
+            if at_risk_courses.contains(code) {
+                flags.push_str(", at risk: uncertain offering");
+            }
+            println!("  {} ({} credits{})", code, credits, flags);
             sem_credits += credits;
         }
         println!("  Credits: {}", sem_credits);
@@ -57,11 +151,14 @@ fn main() -> Result<()> {
     }
     println!("Total credits: {}", sched_credits);
 
+    let report = sched.validate()?;
+    println!("{}", report);
+
     save_schedule(&Path::new(FNAME).to_path_buf(), &sched)?;
 
     println!(
         "Excel file created: {FNAME} with {} schedule",
-        if sched.is_valid()? {
+        if report.is_valid() {
             "valid"
         } else {
             "invalid"
@@ -70,3 +167,46 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Prior credit from a `NAME` or `NAME=CREDITS` argument. Without a credit count, the credit is
+/// worth whatever its catalog equivalents are.
+fn parse_prior_credit(
+    source: PriorCreditSource,
+    spec: &str,
+    catalog: &Catalog,
+) -> Result<PriorCredit> {
+    let (name, credits) = match spec.rsplit_once('=') {
+        Some((name, credits)) => (name, Some(credits.trim().parse::<u32>()?)),
+        None => (spec, None),
+    };
+    let mut credit = PriorCredit::new(source, name.trim(), 0, catalog);
+    credit.credits = match credits {
+        Some(credits) => credits,
+        None if !credit.equivalents.is_empty() => credit
+            .equivalents
+            .iter()
+            .map(|code| catalog.min_credits(code))
+            .sum(),
+        None => anyhow::bail!(
+            "{} credit \"{}\" has no equivalent in {}; give its credits as NAME=CREDITS",
+            source,
+            credit.name,
+            catalog
+        ),
+    };
+    Ok(credit)
+}
+
+/// `ross lint`: check every loaded catalog, failing if any has problems.
+fn lint_catalogs(catalogs: &[Catalog]) -> Result<()> {
+    let mut clean = true;
+    for catalog in catalogs {
+        let report = catalog.lint();
+        println!("{}: {}", catalog, report);
+        clean &= report.is_clean();
+    }
+    if !clean {
+        anyhow::bail!("catalog lint failed");
+    }
+    Ok(())
+}
@@ -1,42 +1,108 @@
 //! Context struct for model building and shared state.
-use crate::prereqs::CourseReq;
-use crate::schedule::{Catalog, CourseCode, Schedule};
-use cp_sat::builder::{BoolVar, CpModelBuilder, LinearExpr};
+use super::diagnostics::Requirement;
+use crate::prereqs::{CourseReq, InstructorConsent};
+use crate::schedule::{Catalog, CourseCode, CreditLimits, Elective, Program, Schedule};
+use crate::terms::Term;
+use cp_sat::builder::{BoolVar, CpModelBuilder, IntVar, LinearExpr};
 
 #[derive(Clone)]
-pub struct Course<'a> {
+pub struct Course {
     pub code: CourseCode,
+    /// The least credits the course carries
     pub credits: i64,
+    /// The most credits the course carries; above `credits` only for variable-credit courses
+    pub max_credits: i64,
     pub required: bool,
-    pub geneds: Vec<&'a str>,
-    pub elective_group: Option<&'a str>,
+    pub completed: bool,
     pub prereqs: CourseReq,
 }
 
+/// A course that may count toward a GenEd; `var` is true when the solver counts it there.
+#[derive(Clone)]
+pub struct GenEdAssignment<'a> {
+    pub gened: &'a str,
+    /// Index into `ModelBuilderContext::courses`
+    pub course: usize,
+    pub var: BoolVar,
+}
+
+/// A course that may serve a program; `var` is true when the solver counts it toward that program.
+#[derive(Clone)]
+pub struct ProgramAssignment<'a> {
+    pub program: &'a str,
+    /// Index into `ModelBuilderContext::courses`
+    pub course: usize,
+    pub var: BoolVar,
+}
+
 pub struct ModelBuilderContext<'a> {
     pub model: CpModelBuilder,
     pub vars: Vec<Vec<BoolVar>>,
-    pub courses: Vec<Course<'a>>,
+    /// Credits above the minimum each variable-credit course carries in each semester; `None`
+    /// for fixed-credit courses
+    pub extra_credits: Vec<Option<Vec<IntVar>>>,
+    pub courses: Vec<Course>,
+    /// Number of semesters being planned (locked history semesters are not modeled)
     pub num_semesters: usize,
-    pub max_credits_per_semester: i64,
+    /// The term of each planned semester
+    pub terms: Vec<Term>,
+    /// Credit limits for each planned semester
+    pub credit_limits: Vec<CreditLimits>,
     pub min_credits: Option<i64>,
+    /// Most placements on uncertain offerings allowed, once the fewest possible is known
+    pub max_at_risk: Option<i64>,
     pub geneds: Option<&'a [crate::geneds::GenEd]>,
+    /// Filled in by the GenEd constraints
+    pub gened_assignments: Vec<GenEdAssignment<'a>>,
+    pub programs: Vec<&'a Program>,
+    /// Filled in by the program constraints
+    pub program_assignments: Vec<ProgramAssignment<'a>>,
+    /// Elective trees, each with the name of the program requiring it
+    pub electives: Vec<(&'a str, &'a Elective)>,
     pub catalog: Option<&'a Catalog>,
+    /// The schedule being planned, for its transcript, history and prior credit
+    pub schedule: &'a Schedule,
+    /// Credits already earned before the first planned semester
+    pub completed_credits: i64,
+    pub instructor_consent: &'a InstructorConsent,
+    /// Requirements to enforce; `None` enforces all of them
+    pub enabled: Option<std::collections::HashSet<Requirement>>,
 }
 
 impl<'a> ModelBuilderContext<'a> {
-    /// Create a new ModelBuilderContext from a schedule and max credits per semester.
-    pub fn new(sched: &'a Schedule, max_credits_per_semester: i64) -> Self {
+    /// Create a new ModelBuilderContext from a schedule.
+    pub fn new(sched: &'a Schedule) -> Self {
         // Add all courses in the student's plan, their prerequisites, and all GenEd-eligible courses (as options)
         let mut all_codes = std::collections::HashSet::new();
         let mut queue = std::collections::VecDeque::new();
-        // 1. Add planned courses and their prereqs
+        // 1. Add planned courses, program courses (even completed ones, which still count toward
+        // shared credits), elective candidates, and their prereqs
         for sem in &sched.courses {
             for code in sem {
                 all_codes.insert(code.clone());
                 queue.push_back(code.clone());
             }
         }
+        let programs: Vec<&Program> = sched
+            .catalog
+            .programs
+            .iter()
+            .filter(|p| sched.programs.contains(&p.name))
+            .collect();
+        for prog in &programs {
+            for code in prog.required_courses() {
+                if all_codes.insert(code.clone()) {
+                    queue.push_back(code.clone());
+                }
+            }
+            for elec in &prog.electives {
+                for code in elec.all_course_codes() {
+                    if all_codes.insert(code.clone()) {
+                        queue.push_back(code);
+                    }
+                }
+            }
+        }
         while let Some(code) = queue.pop_front() {
             if let Some(req) = sched.catalog.prereqs.get(&code) {
                 fn collect_prereq_codes(
@@ -52,7 +118,10 @@ impl<'a> ModelBuilderContext<'a> {
                                 collect_prereq_codes(r, all_codes, catalog, queue);
                             }
                         }
-                        PreCourse(code) | CoCourse(code) => {
+                        PreCourse(code)
+                        | CoCourse(code)
+                        | PreCourseGrade(code, _)
+                        | CoCourseGrade(code, _) => {
                             if all_codes.insert(code.clone()) {
                                 queue.push_back(code.clone());
                             }
@@ -72,50 +141,50 @@ impl<'a> ModelBuilderContext<'a> {
                 GenEd::SkillAndPerspective { req, .. } => vec![req],
             };
             for req in reqs {
-                match req {
-                    GenEdReq::Set(codes)
-                    | GenEdReq::Courses { courses: codes, .. }
-                    | GenEdReq::Credits { courses: codes, .. } => {
-                        for code in codes {
-                            all_codes.insert(code.clone());
-                        }
-                    }
-                    GenEdReq::SetOpts(opts) => {
-                        for opt in opts {
-                            for code in opt {
-                                all_codes.insert(code.clone());
-                            }
-                        }
-                    }
+                for code in req.course_codes() {
+                    all_codes.insert(code.clone());
                 }
             }
         }
         // Build Course structs for all codes, and print diagnostics
+        let completed_codes = sched.completed_courses();
         let mut courses = Vec::new();
         let mut total_credits = 0;
         for code in &all_codes {
-            let (credits, prereqs) = match sched.catalog.courses.get(code) {
+            let completed = completed_codes.contains(code);
+            let (credits, max_credits, prereqs) = match sched.catalog.courses.get(code) {
                 Some((_name, credits_opt, _offering)) => {
-                    let credits = credits_opt.unwrap_or(0) as i64;
+                    // A completed course carries the credits the student actually earned
+                    let (min, max) = match credits_opt {
+                        Some(_) if completed => (sched.credits(code), sched.credits(code)),
+                        Some(cr) => (cr.min, cr.max),
+                        None => (0, 0),
+                    };
                     let prereqs = sched
                         .catalog
                         .prereqs
                         .get(code)
                         .cloned()
                         .unwrap_or(CourseReq::NotRequired);
-                    (credits, prereqs)
+                    (min as i64, max as i64, prereqs)
                 }
-                None => (0, CourseReq::NotRequired),
+                None => (0, 0, CourseReq::NotRequired),
             };
             total_credits += credits;
-            // Mark as required only if in student's plan
-            let required = sched.courses.iter().flatten().any(|c| c == code);
+            // Mark as required only if in the unlocked part of the student's plan and not completed
+            let required = !completed
+                && sched
+                    .courses
+                    .iter()
+                    .skip(sched.locked_semesters)
+                    .flatten()
+                    .any(|c| c == code);
             courses.push(Course {
                 code: code.clone(),
                 credits,
+                max_credits,
                 required,
-                geneds: vec![],
-                elective_group: None,
+                completed,
                 prereqs,
             });
         }
@@ -123,13 +192,81 @@ impl<'a> ModelBuilderContext<'a> {
         ModelBuilderContext {
             model: CpModelBuilder::default(),
             vars: Vec::new(),
+            extra_credits: Vec::new(),
             courses,
-            num_semesters: sched.courses.len(),
-            max_credits_per_semester,
+            num_semesters: sched.courses.len() - sched.history().len(),
+            terms: sched.terms[sched.history().len()..].to_vec(),
+            credit_limits: (sched.locked_semesters..sched.courses.len())
+                .map(|s| sched.credit_policy.limits(s, &sched.terms[s]))
+                .collect(),
             min_credits: None,
+            max_at_risk: None,
             geneds: Some(&sched.catalog.geneds),
+            gened_assignments: Vec::new(),
+            electives: programs
+                .iter()
+                .flat_map(|p| p.electives.iter().map(|e| (p.name.as_str(), e)))
+                .collect(),
+            programs,
+            program_assignments: Vec::new(),
             catalog: Some(&sched.catalog),
+            schedule: sched,
+            completed_credits: sched.completed_credits() as i64,
+            instructor_consent: &sched.instructor_consent,
+            enabled: None,
+        }
+    }
+
+    /// The largest per-semester credit cap, for bounding domains.
+    pub fn max_credits_per_semester(&self) -> i64 {
+        self.credit_limits.iter().map(|l| l.max).max().unwrap_or(0) as i64
+    }
+
+    /// Whether `req` is enforced in this model.
+    pub fn enforces(&self, req: &Requirement) -> bool {
+        self.enabled
+            .as_ref()
+            .is_none_or(|enabled| enabled.contains(req))
+    }
+
+    /// 1 if course `idx` is scheduled in any semester or already completed, else 0.
+    pub fn course_taken_expr(&self, idx: usize) -> LinearExpr {
+        if self.courses[idx].completed {
+            return LinearExpr::from(1);
         }
+        self.vars[idx].iter().copied().collect()
+    }
+
+    /// Credits course `idx` adds to semester `s`: its minimum if scheduled there, plus whatever
+    /// the solver chooses above that for variable-credit courses.
+    pub fn semester_credits_expr(&self, idx: usize, s: usize) -> LinearExpr {
+        let expr = LinearExpr::from((self.courses[idx].credits, self.vars[idx][s]));
+        match &self.extra_credits[idx] {
+            Some(extra) => expr + extra[s],
+            None => expr,
+        }
+    }
+
+    /// The credits course `idx` carries when `var` is true, and 0 otherwise. Variable-credit
+    /// courses carry whatever amount the solver chooses for them.
+    pub fn credits_if(&mut self, idx: usize, var: BoolVar) -> LinearExpr {
+        course_credits_if(
+            &mut self.model,
+            &self.courses[idx],
+            self.extra_credits[idx].as_deref(),
+            var,
+        )
+    }
+
+    /// Credits earned before semester `s`: completed credits plus everything scheduled earlier.
+    pub fn credits_before_expr(&self, s: usize) -> LinearExpr {
+        let mut expr = LinearExpr::from(self.completed_credits);
+        for i in 0..self.courses.len() {
+            for t in 0..s {
+                expr += self.semester_credits_expr(i, t);
+            }
+        }
+        expr
     }
 
     /// Set the minimum total credits constraint
@@ -137,29 +274,69 @@ impl<'a> ModelBuilderContext<'a> {
         self.min_credits = Some(min_credits);
     }
 
+    /// Number of courses placed on an uncertain (Discretion/Infrequently) offering that a
+    /// reliably offered course could stand in for, each counted `weight` times. Courses with no
+    /// such alternative are left out, since no other plan would avoid them.
+    pub fn at_risk_expr(&self, weight: i64) -> LinearExpr {
+        let mut expr = LinearExpr::from(0);
+        for (i, c) in self.courses.iter().enumerate() {
+            if !self.schedule.reliable_alternatives(&c.code).is_empty() {
+                for s in 0..self.num_semesters {
+                    expr += (weight, self.vars[i][s]);
+                }
+            }
+        }
+        expr
+    }
+
+    /// More credits than any schedule can carry, with every course at its most.
+    pub fn credits_bound(&self) -> i64 {
+        self.courses.iter().map(|c| c.max_credits).sum::<i64>() + 1
+    }
+
     /// Compute the total credits LinearExpr for the current context
-    pub fn total_credits_expr(
-        &self,
-        vars: &Vec<Vec<BoolVar>>,
-        flat_courses: &Vec<(Course, i64)>,
-    ) -> LinearExpr {
-        let mut obj_terms = Vec::new();
-        for (i, (_course, credits)) in flat_courses.iter().enumerate() {
+    pub fn total_credits_expr(&self) -> LinearExpr {
+        let mut expr = LinearExpr::from(0);
+        for i in 0..self.courses.len() {
             for s in 0..self.num_semesters {
-                obj_terms.push((*credits, vars[i][s]));
+                expr += self.semester_credits_expr(i, s);
             }
         }
-        obj_terms.into_iter().collect()
+        expr
     }
 }
 
+/// The credits `course` carries when `var` is true, and 0 otherwise; `extra` holds the credits
+/// chosen above its minimum in each semester, for variable-credit courses.
+pub fn course_credits_if(
+    model: &mut CpModelBuilder,
+    course: &Course,
+    extra: Option<&[IntVar]>,
+    var: BoolVar,
+) -> LinearExpr {
+    let expr = LinearExpr::from((course.credits, var));
+    let Some(extra) = extra else {
+        return expr;
+    };
+    // counted = chosen extra when `var` is true, else 0
+    let span = course.max_credits - course.credits;
+    let chosen: LinearExpr = extra.iter().copied().collect();
+    let counted = model.new_int_var(vec![(0, span)]);
+    model.add_le(counted, LinearExpr::from((span, var)));
+    model.add_le(counted, chosen.clone());
+    model.add_ge(LinearExpr::from(counted) + span, chosen + (span, var));
+    expr + counted
+}
+
 /// Build the model pipeline: add variables, constraints, and return (model, vars, flat_courses)
 pub fn build_model_pipeline<'a>(
     ctx: &mut ModelBuilderContext<'a>,
-) -> (CpModelBuilder, Vec<Vec<BoolVar>>, Vec<(Course<'a>, i64)>) {
+) -> (CpModelBuilder, Vec<Vec<BoolVar>>, Vec<(Course, i64)>) {
     super::courses::add_courses(ctx);
     super::prereqs::add_prereq_constraints(ctx);
     super::geneds::add_gened_constraints(ctx);
+    super::programs::add_program_constraints(ctx);
+    super::electives::add_elective_constraints(ctx);
     super::semester::add_semester_constraints(ctx);
     // Build flat_courses as (Course, credits)
     let flat_courses = ctx.courses.iter().map(|c| (c.clone(), c.credits)).collect();
@@ -169,3 +346,32 @@ pub fn build_model_pipeline<'a>(
         flat_courses,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::tests::{catalog, code, schedule};
+
+    #[test]
+    fn locked_semesters_are_history_not_variables() {
+        let catalog = catalog(serde_json::json!({
+            "courses": {
+                "CHEM-1200": ["General Chemistry I", 4, "Both"],
+                "CHEM-1300": ["General Chemistry II", 4, "Both"]
+            }
+        }));
+        let mut sched = schedule(
+            catalog,
+            &[],
+            vec![vec![code("CHEM-1200")], vec![code("CHEM-1300")], vec![]],
+        );
+        sched.locked_semesters = 1;
+        let ctx = ModelBuilderContext::new(&sched);
+        assert_eq!(ctx.num_semesters, 2);
+        assert_eq!(ctx.terms, sched.terms[1..]);
+        assert_eq!(ctx.completed_credits, 4);
+        let course = |c: &str| ctx.courses.iter().find(|x| x.code == code(c)).unwrap();
+        assert!(course("CHEM-1200").completed && !course("CHEM-1200").required);
+        assert!(!course("CHEM-1300").completed && course("CHEM-1300").required);
+    }
+}
@@ -12,6 +12,26 @@ pub fn add_courses<'a>(ctx: &mut ModelBuilderContext<'a>) {
         vars.push(sem_vars);
     }
     ctx.vars = vars;
+    // Variable-credit courses choose how far above their minimum to go, only where scheduled
+    let mut extra_credits = Vec::new();
+    for i in 0..ctx.courses.len() {
+        let span = ctx.courses[i].max_credits - ctx.courses[i].credits;
+        if span == 0 || ctx.courses[i].completed {
+            extra_credits.push(None);
+            continue;
+        }
+        let mut sem_extra = Vec::new();
+        for s in 0..ctx.num_semesters {
+            let extra = ctx.model.new_int_var(vec![(0, span)]);
+            ctx.model.add_le(
+                extra,
+                cp_sat::builder::LinearExpr::from((span, ctx.vars[i][s])),
+            );
+            sem_extra.push(extra);
+        }
+        extra_credits.push(Some(sem_extra));
+    }
+    ctx.extra_credits = extra_credits;
     // Required courses exactly once
     for (i, c) in ctx.courses.iter().enumerate() {
         if c.required {
@@ -26,13 +46,17 @@ pub fn add_courses<'a>(ctx: &mut ModelBuilderContext<'a>) {
     }
     // // Enforce maximum total credits if specified
     if let Some(min_credits) = ctx.min_credits {
-        let flat_courses: Vec<_> = ctx.courses.iter().map(|c| (c.clone(), c.credits)).collect();
-        let total_credits_expr = ctx.total_credits_expr(&ctx.vars, &flat_courses);
+        let total_credits_expr = ctx.total_credits_expr();
         ctx.model.add_le(
             total_credits_expr,
             cp_sat::builder::LinearExpr::from(min_credits),
         );
     }
+    // Keep to the fewest uncertain placements once they're known
+    if let Some(max_at_risk) = ctx.max_at_risk {
+        let at_risk_expr = ctx.at_risk_expr(1);
+        ctx.model.add_le(at_risk_expr, max_at_risk);
+    }
     // Enforce term offering constraints for each course
     for (i, c) in ctx.courses.iter().enumerate() {
         // Look up term offering from catalog
@@ -41,16 +65,7 @@ pub fn add_courses<'a>(ctx: &mut ModelBuilderContext<'a>) {
             .and_then(|cat| cat.courses.get(&c.code))
             .map(|(_, _, off)| off);
         for s in 0..ctx.num_semesters {
-            let allowed = match offering {
-                Some(crate::schedule::CourseTermOffering::Fall) => s % 2 == 0, // even semesters
-                Some(crate::schedule::CourseTermOffering::Spring) => s % 2 == 1, // odd semesters
-                Some(crate::schedule::CourseTermOffering::Both) => true,
-                Some(crate::schedule::CourseTermOffering::Discretion) => true, // allowed, but may change in future
-                Some(crate::schedule::CourseTermOffering::Infrequently) => true, // allowed, but may change in future
-                Some(crate::schedule::CourseTermOffering::Summer) => false,      // never schedule
-                None => true,                                                    // default: allow
-            };
-            if !allowed {
+            if !ctx.terms[s].offers(offering) {
                 // Forbid scheduling this course in this semester
                 ctx.model
                     .add_eq(ctx.vars[i][s], cp_sat::builder::LinearExpr::from(0));
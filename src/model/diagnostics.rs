@@ -0,0 +1,469 @@
+//! Explaining why no feasible schedule exists, by relaxing requirements and reporting the ones
+//! that conflict.
+use super::context::{ModelBuilderContext, build_model_pipeline};
+use crate::prereq_graph::PrereqGraph;
+use crate::schedule::{CourseCode, CourseTermOffering, Schedule};
+use cp_sat::proto::CpSolverStatus;
+use std::collections::HashSet;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Solver time one relaxed sub-solve may take.
+const SUB_SOLVE_TIME_LIMIT: Duration = Duration::from_secs(10);
+/// Solver time the whole diagnosis may take, across all of its sub-solves.
+const DIAGNOSIS_TIME_LIMIT: Duration = Duration::from_secs(60);
+
+/// A requirement that can be relaxed (left out of the model) while diagnosing infeasibility.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum Requirement {
+    /// A course from the student's plan must be scheduled
+    Course(CourseCode),
+    /// A gened, by name, must be satisfied
+    GenEd(String),
+    /// A program's electives must be satisfied
+    Electives(String),
+    /// Every planned semester must reach its minimum credits
+    CreditMinimum,
+    /// Programs must stay within their shared-credit limits
+    SharedCredits,
+}
+
+/// Why a requirement could not be met.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conflict {
+    /// A required course is not offered in any of the remaining semesters
+    NotOffered {
+        course: CourseCode,
+        offering: CourseTermOffering,
+    },
+    /// A required course's prerequisite chain needs more semesters than remain
+    PrereqChain {
+        course: CourseCode,
+        semesters_needed: usize,
+        semesters_left: usize,
+    },
+    /// A required course can't be scheduled alongside the other conflicting requirements
+    Course(CourseCode),
+    /// A gened can't be met within the remaining semesters and credit cap
+    GenEd(String),
+    /// A program's electives can't be met within the remaining semesters and credit cap
+    Electives(String),
+    /// Not enough schedulable courses to reach every semester's minimum credits
+    CreditMinimum,
+    /// The student's programs can't be met without sharing more credits than they allow
+    SharedCredits,
+    /// Two programs require courses in common that carry more credits than they may share
+    RequiredOverlap {
+        programs: (String, String),
+        credits: u32,
+        max: u32,
+    },
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Conflict::NotOffered { course, offering } => write!(
+                f,
+                "{} is offered only {:?}, which no remaining semester is",
+                course, offering
+            ),
+            Conflict::PrereqChain {
+                course,
+                semesters_needed,
+                semesters_left,
+            } => write!(
+                f,
+                "{} needs a prerequisite chain of {} semesters, but only {} remain",
+                course, semesters_needed, semesters_left
+            ),
+            Conflict::Course(course) => write!(f, "{} cannot be scheduled", course),
+            Conflict::GenEd(name) => write!(f, "gened {} cannot be met", name),
+            Conflict::Electives(program) => {
+                write!(f, "electives for {} cannot be met", program)
+            }
+            Conflict::CreditMinimum => {
+                write!(f, "per-semester minimum credits cannot be reached")
+            }
+            Conflict::SharedCredits => {
+                write!(
+                    f,
+                    "programs cannot be met within their shared-credit limits"
+                )
+            }
+            Conflict::RequiredOverlap {
+                programs: (program, other),
+                credits,
+                max,
+            } => write!(
+                f,
+                "{} and {} both require {} credits of courses but may share at most {}",
+                program, other, credits, max
+            ),
+        }
+    }
+}
+
+/// Why the solver found no schedule: a minimal set of requirements that cannot all be met.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfeasibilityReport {
+    pub conflicts: Vec<Conflict>,
+    pub semesters_left: usize,
+    pub max_credits_per_semester: i64,
+}
+
+impl fmt::Display for InfeasibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "No feasible schedule in {} semesters at up to {} credits per semester",
+            self.semesters_left, self.max_credits_per_semester
+        )?;
+        if self.conflicts.is_empty() {
+            return write!(f, " (no conflicting requirements identified)");
+        }
+        write!(f, ":")?;
+        for conflict in &self.conflicts {
+            write!(f, "\n  - {}", conflict)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InfeasibilityReport {}
+
+/// Find the requirements responsible for an infeasible schedule.
+///
+/// Requirements that are infeasible on their own are reported directly. Otherwise, each
+/// requirement is relaxed in turn and stays relaxed unless the rest become feasible, leaving a
+/// minimal conflicting set. A sub-solve that times out proves nothing, so it never gets a
+/// requirement blamed. Once [`DIAGNOSIS_TIME_LIMIT`] runs out, the requirements not yet
+/// relaxed are reported as they stand, so the set may not be minimal. Programs whose common
+/// required courses exceed their shared-credit limit are reported without solving.
+pub fn diagnose_infeasibility(sched: &Schedule) -> InfeasibilityReport {
+    let deadline = Instant::now() + DIAGNOSIS_TIME_LIMIT;
+    let ctx = ModelBuilderContext::new(sched);
+    let overlaps = required_overlaps(sched);
+    if !overlaps.is_empty() {
+        return InfeasibilityReport {
+            conflicts: overlaps,
+            semesters_left: ctx.num_semesters,
+            max_credits_per_semester: ctx.max_credits_per_semester(),
+        };
+    }
+    let mut reqs: Vec<Requirement> = ctx
+        .courses
+        .iter()
+        .filter(|c| c.required)
+        .map(|c| Requirement::Course(c.code.clone()))
+        .collect();
+    reqs.extend(
+        sched
+            .catalog
+            .geneds
+            .iter()
+            .map(|g| Requirement::GenEd(g.name().to_string())),
+    );
+    for (program, _) in &ctx.electives {
+        let req = Requirement::Electives(program.to_string());
+        if !reqs.contains(&req) {
+            reqs.push(req);
+        }
+    }
+    reqs.push(Requirement::CreditMinimum);
+    if ctx.programs.len() > 1 {
+        reqs.push(Requirement::SharedCredits);
+    }
+
+    let mut conflicting: Vec<Requirement> = reqs
+        .iter()
+        .filter(|req| feasibility(sched, [(*req).clone()], deadline) == Some(false))
+        .cloned()
+        .collect();
+    if conflicting.is_empty() && feasibility(sched, reqs.clone(), deadline) == Some(false) {
+        conflicting = reqs;
+        let mut i = 0;
+        while i < conflicting.len() && Instant::now() < deadline {
+            let relaxed = conflicting.remove(i);
+            if feasibility(sched, conflicting.clone(), deadline) == Some(true) {
+                conflicting.insert(i, relaxed);
+                i += 1;
+            }
+        }
+    }
+
+    InfeasibilityReport {
+        conflicts: conflicting.iter().map(|req| explain(&ctx, req)).collect(),
+        semesters_left: ctx.num_semesters,
+        max_credits_per_semester: ctx.max_credits_per_semester(),
+    }
+}
+
+/// Pairs of the student's programs whose common required courses alone carry more credits than
+/// the programs may share. Required courses always count toward their program, so no choice of
+/// electives gets such a pair within its limit.
+fn required_overlaps(sched: &Schedule) -> Vec<Conflict> {
+    let programs: Vec<_> = sched
+        .catalog
+        .programs
+        .iter()
+        .filter(|p| sched.programs.contains(&p.name))
+        .collect();
+    let mut overlaps = vec![];
+    for (i, program) in programs.iter().enumerate() {
+        for other in &programs[i + 1..] {
+            let Some(max) = [program.max_shared_credits, other.max_shared_credits]
+                .into_iter()
+                .flatten()
+                .min()
+            else {
+                continue;
+            };
+            let required: HashSet<_> = other.required_courses().collect();
+            let credits = program
+                .required_courses()
+                .collect::<HashSet<_>>()
+                .intersection(&required)
+                .map(|code| sched.catalog.min_credits(code))
+                .sum();
+            if credits > max {
+                overlaps.push(Conflict::RequiredOverlap {
+                    programs: (program.name.clone(), other.name.clone()),
+                    credits,
+                    max,
+                });
+            }
+        }
+    }
+    overlaps
+}
+
+/// Whether a schedule exists when only `enabled` requirements are enforced, or `None` if the
+/// solver couldn't tell before its time limit or the `deadline`.
+fn feasibility(
+    sched: &Schedule,
+    enabled: impl IntoIterator<Item = Requirement>,
+    deadline: Instant,
+) -> Option<bool> {
+    let time_left = deadline.saturating_duration_since(Instant::now());
+    if time_left.is_zero() {
+        return None;
+    }
+    let params = cp_sat::proto::SatParameters {
+        log_search_progress: Some(false),
+        num_search_workers: Some(8),
+        max_time_in_seconds: Some(time_left.min(SUB_SOLVE_TIME_LIMIT).as_secs_f64()),
+        ..Default::default()
+    };
+    let mut ctx = ModelBuilderContext::new(sched);
+    ctx.enabled = Some(enabled.into_iter().collect());
+    // Relaxed plan courses become optional
+    for i in 0..ctx.courses.len() {
+        let course_req = Requirement::Course(ctx.courses[i].code.clone());
+        if !ctx.enforces(&course_req) {
+            ctx.courses[i].required = false;
+        }
+    }
+    let (model, _, _) = build_model_pipeline(&mut ctx);
+    let response = model.solve_with_parameters(&params);
+    match response.status() {
+        CpSolverStatus::Optimal | CpSolverStatus::Feasible => Some(true),
+        CpSolverStatus::Infeasible => Some(false),
+        _ => None,
+    }
+}
+
+/// Turn a conflicting requirement into the most specific explanation available.
+fn explain(ctx: &ModelBuilderContext, req: &Requirement) -> Conflict {
+    match req {
+        Requirement::Course(code) => {
+            let offering = ctx
+                .catalog
+                .and_then(|cat| cat.courses.get(code))
+                .map(|(_, _, off)| off);
+            if let Some(offering) = offering {
+                let offered = ctx.terms.iter().any(|t| t.offers(Some(offering)));
+                if !offered {
+                    return Conflict::NotOffered {
+                        course: code.clone(),
+                        offering: offering.clone(),
+                    };
+                }
+            }
+            let schedule = ctx.schedule;
+            let min_sems =
+                PrereqGraph::new(&schedule.catalog).min_semesters(&schedule.completed_courses());
+            if let Some(&semesters_needed) = min_sems.get(code)
+                && semesters_needed > ctx.num_semesters
+            {
+                return Conflict::PrereqChain {
+                    course: code.clone(),
+                    semesters_needed,
+                    semesters_left: ctx.num_semesters,
+                };
+            }
+            Conflict::Course(code.clone())
+        }
+        Requirement::GenEd(name) => Conflict::GenEd(name.clone()),
+        Requirement::Electives(program) => Conflict::Electives(program.clone()),
+        Requirement::CreditMinimum => Conflict::CreditMinimum,
+        Requirement::SharedCredits => Conflict::SharedCredits,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geneds::GenEdOverlapPolicy;
+    use crate::prereqs::CourseReq;
+    use crate::schedule::tests::{code, schedule};
+    use crate::schedule::{Catalog, CreditRange, Program, ProgramKind};
+    use std::collections::HashMap;
+
+    fn no_overlap_limits() -> GenEdOverlapPolicy {
+        GenEdOverlapPolicy {
+            max_core: None,
+            max_foundations: None,
+            max_skills_and_perspectives: None,
+            allowed_foundation_pairs: vec![],
+        }
+    }
+
+    /// A one-semester fall plan for a minor's PHYS-3100, which needs three semesters counting its
+    /// prerequisites, and PHYS-3200, which is only offered in the spring.
+    fn infeasible_minor() -> Schedule {
+        let course =
+            |name: &str, offering| (name.to_string(), Some(CreditRange::fixed(3)), offering);
+        let catalog = Catalog {
+            programs: vec![Program {
+                name: "Physics Minor".to_string(),
+                kind: ProgramKind::Minor,
+                semesters: vec![],
+                required: vec![code("PHYS-3100"), code("PHYS-3200")],
+                electives: vec![],
+                assoc_stems: vec![],
+                max_shared_credits: None,
+            }],
+            geneds: vec![],
+            gened_overlap: no_overlap_limits(),
+            prereqs: HashMap::from([
+                (code("PHYS-2100"), CourseReq::PreCourse(code("PHYS-1100"))),
+                (code("PHYS-3100"), CourseReq::PreCourse(code("PHYS-2100"))),
+            ]),
+            courses: HashMap::from([
+                (
+                    code("PHYS-1100"),
+                    course("Mechanics", CourseTermOffering::Both),
+                ),
+                (
+                    code("PHYS-2100"),
+                    course("Modern Physics", CourseTermOffering::Both),
+                ),
+                (
+                    code("PHYS-3100"),
+                    course("Quantum Mechanics", CourseTermOffering::Both),
+                ),
+                (
+                    code("PHYS-3200"),
+                    course("Optics", CourseTermOffering::Spring),
+                ),
+            ]),
+            equivalencies: vec![],
+            low_year: 2025,
+        };
+        schedule(
+            catalog,
+            &["Physics Minor"],
+            vec![vec![code("PHYS-3100"), code("PHYS-3200")]],
+        )
+    }
+
+    #[test]
+    fn explains_courses_by_offering_and_prerequisite_chain() {
+        let sched = infeasible_minor();
+        let ctx = ModelBuilderContext::new(&sched);
+        assert_eq!(
+            explain(&ctx, &Requirement::Course(code("PHYS-3100"))),
+            Conflict::PrereqChain {
+                course: code("PHYS-3100"),
+                semesters_needed: 3,
+                semesters_left: 1,
+            }
+        );
+        assert_eq!(
+            explain(&ctx, &Requirement::Course(code("PHYS-3200"))),
+            Conflict::NotOffered {
+                course: code("PHYS-3200"),
+                offering: CourseTermOffering::Spring,
+            }
+        );
+        assert_eq!(
+            explain(&ctx, &Requirement::Course(code("PHYS-1100"))),
+            Conflict::Course(code("PHYS-1100"))
+        );
+    }
+
+    #[test]
+    fn required_courses_beyond_the_shared_credit_limit_are_reported() {
+        let course = |name: &str| {
+            (
+                name.to_string(),
+                Some(CreditRange::fixed(4)),
+                CourseTermOffering::Both,
+            )
+        };
+        let program = |name: &str, kind, max_shared_credits| Program {
+            name: name.to_string(),
+            kind,
+            semesters: vec![],
+            required: vec![code("CHEM-1200"), code("CHEM-1300")],
+            electives: vec![],
+            assoc_stems: vec![],
+            max_shared_credits,
+        };
+        let catalog = Catalog {
+            programs: vec![
+                program("BS Biochemistry", ProgramKind::Major, None),
+                program("Chemistry Minor", ProgramKind::Minor, Some(4)),
+            ],
+            geneds: vec![],
+            gened_overlap: no_overlap_limits(),
+            prereqs: HashMap::new(),
+            courses: HashMap::from([
+                (code("CHEM-1200"), course("General Chemistry I")),
+                (code("CHEM-1300"), course("General Chemistry II")),
+            ]),
+            equivalencies: vec![],
+            low_year: 2025,
+        };
+        let sched = schedule(
+            catalog,
+            &["BS Biochemistry", "Chemistry Minor"],
+            vec![vec![], vec![]],
+        );
+        let report = diagnose_infeasibility(&sched);
+        assert_eq!(
+            report.conflicts,
+            vec![Conflict::RequiredOverlap {
+                programs: ("BS Biochemistry".to_string(), "Chemistry Minor".to_string()),
+                credits: 8,
+                max: 4,
+            }]
+        );
+    }
+
+    #[test]
+    fn diagnoses_an_infeasible_catalog() {
+        let report = diagnose_infeasibility(&infeasible_minor());
+        assert_eq!(report.semesters_left, 1);
+        assert!(report.conflicts.contains(&Conflict::PrereqChain {
+            course: code("PHYS-3100"),
+            semesters_needed: 3,
+            semesters_left: 1,
+        }));
+        assert!(report.conflicts.contains(&Conflict::NotOffered {
+            course: code("PHYS-3200"),
+            offering: CourseTermOffering::Spring,
+        }));
+    }
+}
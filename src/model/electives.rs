@@ -0,0 +1,167 @@
+//! Functions for adding program elective constraints.
+use super::context::ModelBuilderContext;
+use super::diagnostics::Requirement;
+use crate::schedule::{CourseCode, Elective};
+use cp_sat::builder::{BoolVar, LinearExpr};
+use std::collections::HashMap;
+
+/// Add elective constraints to the model: every elective tree of every program must be satisfied
+/// by courses serving that program.
+pub fn add_elective_constraints<'a>(ctx: &mut ModelBuilderContext<'a>) {
+    let idx_map: HashMap<_, _> = ctx
+        .courses
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.code.clone(), i))
+        .collect();
+    let serving: HashMap<(&str, usize), BoolVar> = ctx
+        .program_assignments
+        .iter()
+        .map(|a| ((a.program, a.course), a.var))
+        .collect();
+    // Avoid borrow checker issues: collect electives first, leaving out relaxed programs
+    let electives: Vec<(&str, Elective)> = ctx
+        .electives
+        .iter()
+        .filter(|(program, _)| ctx.enforces(&Requirement::Electives(program.to_string())))
+        .map(|(program, e)| (*program, (*e).clone()))
+        .collect();
+    for (program, elec) in electives.iter() {
+        let serving = |idx: usize| serving.get(&(*program, idx)).copied();
+        let sat = add_elective_node(ctx, &idx_map, &serving, elec);
+        ctx.model.add_eq(sat, 1);
+    }
+}
+
+/// Return a bool var that can only be true if the elective (sub)tree is satisfied.
+fn add_elective_node<'a>(
+    ctx: &mut ModelBuilderContext<'a>,
+    idx_map: &HashMap<CourseCode, usize>,
+    serving: &dyn Fn(usize) -> Option<BoolVar>,
+    elec: &Elective,
+) -> BoolVar {
+    let sat = ctx.model.new_bool_var();
+    match elec {
+        Elective::And(reqs) => {
+            for r in reqs {
+                let child = add_elective_node(ctx, idx_map, serving, r);
+                ctx.model.add_le(sat, child);
+            }
+        }
+        Elective::Or(reqs) => {
+            let children: Vec<_> = reqs
+                .iter()
+                .map(|r| add_elective_node(ctx, idx_map, serving, r))
+                .collect();
+            let sum_or: LinearExpr = children.into_iter().collect();
+            ctx.model.add_ge(sum_or, sat);
+        }
+        Elective::Courses { num, opts } => {
+            let taken = taken_expr(idx_map, serving, opts);
+            ctx.model
+                .add_ge(taken, LinearExpr::from((*num as i64, sat)));
+        }
+        Elective::Credits { num, opts } => {
+            let taken = credits_expr(ctx, idx_map, serving, opts);
+            ctx.model
+                .add_ge(taken, LinearExpr::from((*num as i64, sat)));
+        }
+        Elective::Sequence(seqs) => {
+            let mut seq_vars = Vec::new();
+            for seq in seqs {
+                let seq_var = ctx.model.new_bool_var();
+                let taken = taken_expr(idx_map, serving, seq);
+                ctx.model
+                    .add_ge(taken, LinearExpr::from((seq.len() as i64, seq_var)));
+                seq_vars.push(seq_var);
+            }
+            let sum_seq: LinearExpr = seq_vars.into_iter().collect();
+            ctx.model.add_ge(sum_seq, sat);
+        }
+    }
+    sat
+}
+
+/// Count of courses among `codes` that serve the program (scheduled or already passed); codes
+/// missing from the model contribute nothing.
+fn taken_expr(
+    idx_map: &HashMap<CourseCode, usize>,
+    serving: &dyn Fn(usize) -> Option<BoolVar>,
+    codes: &[CourseCode],
+) -> LinearExpr {
+    codes
+        .iter()
+        .filter_map(|code| serving(*idx_map.get(code)?))
+        .collect()
+}
+
+/// Credits of the courses among `codes` that serve the program, counting what the solver chooses
+/// for variable-credit courses.
+fn credits_expr<'a>(
+    ctx: &mut ModelBuilderContext<'a>,
+    idx_map: &HashMap<CourseCode, usize>,
+    serving: &dyn Fn(usize) -> Option<BoolVar>,
+    codes: &[CourseCode],
+) -> LinearExpr {
+    let mut expr = LinearExpr::from(0);
+    for code in codes {
+        if let Some(&idx) = idx_map.get(code)
+            && let Some(var) = serving(idx)
+        {
+            expr += ctx.credits_if(idx, var);
+        }
+    }
+    expr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::build_model_pipeline;
+    use crate::schedule::tests::{catalog, code, schedule};
+
+    #[test]
+    fn elective_options_join_the_model_as_optional_courses() {
+        let catalog = catalog(serde_json::json!({
+            "programs": [
+                {"name": "BA Mathematics", "kind": "Major", "semesters": [["MATH-1300"]],
+                 "electives": [{"Or": [
+                     {"Courses": {"num": 1, "opts": ["MATH-3100", "MATH-3200"]}},
+                     {"Sequence": [["MATH-4100", "MATH-4200"]]}
+                 ]}]}
+            ],
+            "courses": {
+                "MATH-1300": ["Calculus I", 4, "Both"],
+                "MATH-3100": ["Real Analysis", 3, "Fall"],
+                "MATH-3200": ["Abstract Algebra", 3, "Spring"],
+                "MATH-4100": ["Topology I", 3, "Fall"],
+                "MATH-4200": ["Topology II", 3, "Spring"]
+            }
+        }));
+        let sched = schedule(
+            catalog,
+            &["BA Mathematics"],
+            vec![vec![code("MATH-1300")], vec![]],
+        );
+        let mut ctx = ModelBuilderContext::new(&sched);
+        build_model_pipeline(&mut ctx);
+
+        assert_eq!(ctx.electives.len(), 1);
+        for option in ["MATH-3100", "MATH-3200", "MATH-4100", "MATH-4200"] {
+            let idx = ctx
+                .courses
+                .iter()
+                .position(|c| c.code == code(option))
+                .unwrap_or_else(|| panic!("{} is not in the model", option));
+            assert!(!ctx.courses[idx].required, "{} should be optional", option);
+            // Options only count toward the program when the solver assigns them there
+            assert!(
+                ctx.program_assignments
+                    .iter()
+                    .any(|a| a.program == "BA Mathematics" && a.course == idx)
+            );
+        }
+        let calculus = ctx.courses.iter().find(|c| c.code == code("MATH-1300"));
+        assert!(calculus.is_some_and(|c| c.required));
+    }
+}
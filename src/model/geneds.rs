@@ -1,29 +1,40 @@
 //! Functions for adding GenEd constraints.
-use super::context::Course;
-use super::context::ModelBuilderContext;
-use crate::geneds::{GenEd, GenEdReq};
-use cp_sat::builder::LinearExpr;
+//!
+//! GenEds are modeled as an assignment of courses to GenEds: each GenEd must be met by the courses assigned to it,
+//! and the overlap rules limit how many GenEds one course may be assigned to. The validator solves the same
+//! formulation over a finished schedule, so the two can't disagree about what satisfies the GenEds.
+use super::context::{course_credits_if, GenEdAssignment, ModelBuilderContext};
+use super::diagnostics::Requirement;
+use crate::geneds::{GenEd, GenEdOverlapPolicy, GenEdReq};
+use crate::schedule::{CourseCode, Schedule};
+use cp_sat::builder::{BoolVar, CpModelBuilder, LinearExpr};
+use cp_sat::proto::CpSolverStatus;
+use std::collections::HashMap;
 
 /// Add GenEd constraints to the model.
 pub fn add_gened_constraints<'a>(ctx: &mut ModelBuilderContext<'a>) {
-    let model = &mut ctx.model;
+    // GenEds relaxed away (e.g. while diagnosing infeasibility) are left out entirely
+    let geneds: Vec<&GenEd> = match ctx.catalog {
+        Some(catalog) => catalog.geneds.iter().filter(|g| ctx.enforces(&Requirement::GenEd(g.name().to_string()))).collect(),
+        None => return,
+    };
     let courses = &ctx.courses;
     let vars = &ctx.vars;
     let num_semesters = ctx.num_semesters;
-    let geneds = match ctx.catalog {
-        Some(catalog) => &catalog.geneds,
-        None => return,
-    };
 
-    // Helper: for a course code, find its index in flat_courses
-    let code_to_idx: std::collections::HashMap<_, _> = courses
-        .iter()
-        .enumerate()
-        .map(|(i, course)| (course.code.clone(), i))
-        .collect();
+    let code_to_idx: HashMap<_, _> = courses.iter().enumerate().map(|(i, course)| (course.code.clone(), i)).collect();
+    // Variable-credit courses count the credits the solver chooses for them, as they do in the validator
+    let extra_credits = &ctx.extra_credits;
+    let mut credits_if = |model: &mut CpModelBuilder, idx: usize, var: BoolVar| {
+        course_credits_if(model, &courses[idx], extra_credits[idx].as_deref(), var)
+    };
 
-    // Helper: for a course code, return a variable that is 1 if the course is scheduled in any semester
+    // A course counts if it is scheduled in any semester
+    // (courses already passed always count once, even if a retake is scheduled)
     let course_in_schedule = |idx: usize| {
+        if courses[idx].completed {
+            return LinearExpr::from(1);
+        }
         let mut expr = LinearExpr::from(0);
         for s in 0..num_semesters {
             expr = expr + LinearExpr::from(vars[idx][s].clone());
@@ -31,445 +42,208 @@ pub fn add_gened_constraints<'a>(ctx: &mut ModelBuilderContext<'a>) {
         expr
     };
 
-    // Helper: for a set of course codes, return a vector of their indices (if all present)
-    let codes_to_indices = |codes: &Vec<crate::schedule::CourseCode>| -> Option<Vec<usize>> {
-        codes.iter().map(|c| code_to_idx.get(c).copied()).collect()
-    };
+    let policy = &ctx.schedule.catalog.gened_overlap;
+    ctx.gened_assignments = add_assignment_constraints(&mut ctx.model, &geneds, policy, &code_to_idx, &mut credits_if, &course_in_schedule);
+}
 
-    // --- Core GenEds: no overlap restrictions ---
-    for gened in geneds.iter() {
-        if let GenEd::Core { req, .. } = gened {
-            match req {
-                GenEdReq::Set(codes) => {
-                    if let Some(indices) = codes_to_indices(codes) {
-                        for idx in indices {
-                            // Each course must be scheduled somewhere
-                            model.add_ge(course_in_schedule(idx), LinearExpr::from(1));
-                        }
-                    }
-                }
-                GenEdReq::SetOpts(opts) => {
-                    // At least one option set must be fully present
-                    let mut option_exprs = Vec::new();
-                    for opt in opts {
-                        if let Some(indices) = codes_to_indices(opt) {
-                            // All courses in this option must be present
-                            let mut all_present = LinearExpr::from(0);
-                            for idx in indices {
-                                all_present = all_present + course_in_schedule(idx);
-                            }
-                            // If all are present, sum == len
-                            // Use a bool var to represent this option
-                            let opt_var = model.new_bool_var();
-                            // model.add_le(all_present.clone(), LinearExpr::from(opt.len() as i64) + (LinearExpr::from(1) - opt_var.clone()) * (opt.len() as i64));
-                            // model.add_ge(all_present, LinearExpr::from(opt.len() as i64) - (LinearExpr::from(1) - opt_var.clone()) * (opt.len() as i64));
-                            // Instead, expand (LinearExpr * n) as repeated addition
-                            let mut le_expr = LinearExpr::from(1) - opt_var.clone();
-                            let mut le_scaled = LinearExpr::from(0);
-                            for _ in 0..opt.len() {
-                                le_scaled = le_scaled + le_expr.clone();
-                            }
-                            model.add_le(all_present.clone(), LinearExpr::from(opt.len() as i64) + le_scaled);
-                            let mut ge_scaled = LinearExpr::from(0);
-                            for _ in 0..opt.len() {
-                                ge_scaled = ge_scaled + le_expr.clone();
-                            }
-                            model.add_ge(all_present, LinearExpr::from(opt.len() as i64) - ge_scaled);
-                            option_exprs.push(opt_var);
-                        }
-                    }
-                    if !option_exprs.is_empty() {
-                        let mut sum = LinearExpr::from(0);
-                        for v in option_exprs { sum = sum + LinearExpr::from(v); }
-                        model.add_ge(sum, LinearExpr::from(1));
-                    }
-                }
-                GenEdReq::Courses { num, courses } => {
-                    if let Some(indices) = codes_to_indices(courses) {
-                        let mut sum = LinearExpr::from(0);
-                        for idx in indices { sum = sum + course_in_schedule(idx); }
-                        model.add_ge(sum, LinearExpr::from(*num as i64));
-                    }
-                }
-                GenEdReq::Credits { num, courses } => {
-                    if let Some(indices) = codes_to_indices(courses) {
-                        let mut sum = LinearExpr::from(0);
-                        for idx in indices.iter() {
-                            let credits = ctx.courses[*idx].credits;
-                            // sum = sum + course_in_schedule(*idx) * credits;
-                            if credits > 0 {
-                                for _ in 0..credits {
-                                    sum = sum + course_in_schedule(*idx);
-                                }
-                            } else if credits < 0 {
-                                for _ in 0..(-credits) {
-                                    sum = sum - course_in_schedule(*idx);
-                                }
-                            }
-                        }
-                        model.add_ge(sum, LinearExpr::from(*num as i64));
-                    }
+/// Assign the `taken` courses of `sched` to `geneds` with the same formulation the scheduler uses, or `None` if
+/// the GenEds can't all be met at once.
+pub fn assign_geneds(geneds: &[&GenEd], taken: &[&CourseCode], sched: &Schedule) -> Option<HashMap<CourseCode, Vec<String>>> {
+    let mut model = CpModelBuilder::default();
+    let code_to_idx: HashMap<CourseCode, usize> = taken.iter().enumerate().map(|(i, code)| ((*code).clone(), i)).collect();
+    let credits: Vec<i64> = taken.iter().map(|code| sched.credits(code) as i64).collect();
+    let mut credits_if = |_: &mut CpModelBuilder, idx: usize, var: BoolVar| LinearExpr::from((credits[idx], var));
+    let assignments = add_assignment_constraints(&mut model, geneds, &sched.catalog.gened_overlap, &code_to_idx, &mut credits_if, &|_| LinearExpr::from(1));
+
+    let params = cp_sat::proto::SatParameters { log_search_progress: Some(false), ..Default::default() };
+    let response = model.solve_with_parameters(&params);
+    match response.status() {
+        CpSolverStatus::Optimal | CpSolverStatus::Feasible => {
+            let mut assigned: HashMap<CourseCode, Vec<String>> = HashMap::new();
+            for assignment in assignments {
+                if assignment.var.solution_value(&response) {
+                    assigned.entry(taken[assignment.course].clone()).or_default().push(assignment.gened.to_string());
                 }
             }
+            Some(assigned)
         }
+        _ => None,
     }
+}
 
-    // --- Foundation GenEds: enforce required number/credits, no course may satisfy more than one Foundation ---
-    // For each Foundation, build a set of eligible courses and add a hard constraint for the requirement
-    let mut foundation_sets = Vec::new();
-    for gened in geneds.iter() {
-        if let GenEd::Foundation { req, .. } = gened {
-            match req {
-                GenEdReq::Set(codes) => {
-                    if let Some(indices) = codes_to_indices(codes) {
-                        // All courses in the set must be scheduled
-                        for idx in &indices {
-                            model.add_ge(course_in_schedule(*idx), LinearExpr::from(1));
-                        }
-                        foundation_sets.push(indices);
-                    }
-                }
-                GenEdReq::SetOpts(opts) => {
-                    // At least one option set must be fully present
-                    let mut option_exprs = Vec::new();
-                    let mut all_indices = Vec::new();
-                    for opt in opts {
-                        if let Some(indices) = codes_to_indices(opt) {
-                            // All courses in this option must be present
-                            let mut all_present = LinearExpr::from(0);
-                            for idx in &indices {
-                                all_present = all_present + course_in_schedule(*idx);
-                            }
-                            // Use a bool var to represent this option
-                            let opt_var = model.new_bool_var();
-                            let mut le_expr = LinearExpr::from(1) - opt_var.clone();
-                            let mut le_scaled = LinearExpr::from(0);
-                            for _ in 0..opt.len() {
-                                le_scaled = le_scaled + le_expr.clone();
-                            }
-                            model.add_le(all_present.clone(), LinearExpr::from(opt.len() as i64) + le_scaled);
-                            let mut ge_scaled = LinearExpr::from(0);
-                            for _ in 0..opt.len() {
-                                ge_scaled = ge_scaled + le_expr.clone();
-                            }
-                            model.add_ge(all_present, LinearExpr::from(opt.len() as i64) - ge_scaled);
-                            option_exprs.push(opt_var);
-                            all_indices.extend(indices);
-                        }
-                    }
-                    if !option_exprs.is_empty() {
-                        let mut sum = LinearExpr::from(0);
-                        for v in option_exprs { sum = sum + LinearExpr::from(v); }
-                        model.add_ge(sum, LinearExpr::from(1));
-                    }
-                    foundation_sets.push(all_indices);
-                }
-                GenEdReq::Courses { num, courses } => {
-                    if let Some(indices) = codes_to_indices(courses) {
-                        let mut sum = LinearExpr::from(0);
-                        for idx in &indices { sum = sum + course_in_schedule(*idx); }
-                        model.add_ge(sum, LinearExpr::from(*num as i64));
-                        foundation_sets.push(indices);
-                    }
-                }
-                GenEdReq::Credits { num, courses } => {
-                    if let Some(indices) = codes_to_indices(courses) {
-                        let mut sum = LinearExpr::from(0);
-                        for idx in &indices {
-                            let credits = ctx.courses[*idx].credits;
-                            if credits > 0 {
-                                for _ in 0..credits {
-                                    sum = sum + course_in_schedule(*idx);
-                                }
-                            } else if credits < 0 {
-                                for _ in 0..(-credits) {
-                                    sum = sum - course_in_schedule(*idx);
-                                }
-                            }
-                        }
-                        model.add_ge(sum, LinearExpr::from(*num as i64));
-                        foundation_sets.push(indices);
-                    }
+/// Decide which GenEds each course counts toward. Every GenEd must be met by the courses assigned to it, and no
+/// course may be assigned beyond what the overlap `policy` allows.
+fn add_assignment_constraints<'a>(
+    model: &mut CpModelBuilder,
+    geneds: &[&'a GenEd],
+    policy: &GenEdOverlapPolicy,
+    code_to_idx: &HashMap<CourseCode, usize>,
+    credits_if: &mut dyn FnMut(&mut CpModelBuilder, usize, BoolVar) -> LinearExpr,
+    course_in_schedule: &dyn Fn(usize) -> LinearExpr,
+) -> Vec<GenEdAssignment<'a>> {
+    let mut assignments = Vec::new();
+    let mut uses: HashMap<usize, Vec<(&GenEd, BoolVar)>> = HashMap::new();
+    for gened in geneds {
+        let mut assigned = HashMap::new();
+        for code in gened.req().course_codes() {
+            let Some(&idx) = code_to_idx.get(code) else { continue };
+            if assigned.contains_key(&idx) { continue; }
+            // Only a scheduled (or completed) course can count toward anything
+            let var = model.new_bool_var();
+            model.add_le(var, course_in_schedule(idx));
+            assigned.insert(idx, var);
+            assignments.push(GenEdAssignment { gened: gened.name(), course: idx, var });
+            uses.entry(idx).or_default().push((gened, var));
+        }
+        add_assigned_req(model, gened.req(), &assigned, code_to_idx, credits_if);
+    }
+    for course_uses in uses.values() {
+        // Pairs of GenEds that may not share a course
+        for (i, &(gened, var)) in course_uses.iter().enumerate() {
+            for &(other, other_var) in &course_uses[i + 1..] {
+                if !policy.may_share(gened, other) {
+                    model.add_at_most_one([var, other_var]);
                 }
             }
         }
+        // At most the policy's number of GenEds of each kind
+        let mut by_kind: HashMap<std::mem::Discriminant<GenEd>, (Option<usize>, Vec<BoolVar>)> = HashMap::new();
+        for &(gened, var) in course_uses {
+            by_kind.entry(std::mem::discriminant(gened)).or_insert((policy.max_for(gened), vec![])).1.push(var);
+        }
+        for (max, vars) in by_kind.into_values() {
+            if let Some(max) = max.filter(|&max| vars.len() > max) {
+                let sum: LinearExpr = vars.into_iter().collect();
+                model.add_le(sum, LinearExpr::from(max as i64));
+            }
+        }
     }
-    // --- Guarantee feasible, non-overlapping Foundation assignment (stronger set-cover constraints) ---
-    use std::collections::HashSet;
-    let num_foundations = foundation_sets.len();
-    let num_courses = courses.len();
+    assignments
+}
 
-    // For each Foundation, get the set of eligible indices and required number/credits
-    let mut foundation_reqs: Vec<(Vec<usize>, i64, bool, Vec<i64>)> = Vec::new();
-    for (f, set) in foundation_sets.iter().enumerate() {
-        let gened = &geneds.iter().filter(|g| matches!(g, GenEd::Foundation { .. })).nth(f).unwrap();
-        let (required, is_credits, course_credits) = match gened {
-            GenEd::Foundation { req, .. } => match req {
-                GenEdReq::Credits { num, courses } => {
-                    let credits: Vec<_> = set.iter().map(|&idx| ctx.courses[idx].credits as i64).collect();
-                    (*num as i64, true, credits)
-                },
-                GenEdReq::Set(codes) => (set.len() as i64, false, vec![1; set.len()]),
-                GenEdReq::SetOpts(_) | GenEdReq::Courses { .. } => {
-                    (set.len() as i64, false, vec![1; set.len()])
-                },
-            },
-            _ => (set.len() as i64, false, vec![1; set.len()]),
-        };
-        foundation_reqs.push((set.clone(), required, is_credits, course_credits));
-    }
-    // For each Foundation, require that at least the required number of distinct eligible courses/credits are scheduled
-    for (set, required, is_credits, course_credits) in &foundation_reqs {
-        // Split eligible indices into required and non-required
-        let mut required_idxs = Vec::new();
-        let mut optional_idxs = Vec::new();
-        for &idx in set {
-            if ctx.courses[idx].required {
-                required_idxs.push(idx);
-            } else {
-                optional_idxs.push(idx);
+/// Require `req` to be met by the courses assigned to its GenEd (`assigned` maps course index to assignment var).
+fn add_assigned_req(
+    model: &mut CpModelBuilder,
+    req: &GenEdReq,
+    assigned: &HashMap<usize, BoolVar>,
+    code_to_idx: &HashMap<CourseCode, usize>,
+    credits_if: &mut dyn FnMut(&mut CpModelBuilder, usize, BoolVar) -> LinearExpr,
+) {
+    let var_for = |code: &CourseCode| code_to_idx.get(code).and_then(|idx| assigned.get(idx)).copied();
+    match req {
+        GenEdReq::Set(codes) => {
+            for code in codes {
+                match var_for(code) {
+                    Some(var) => model.add_eq(var, LinearExpr::from(1)),
+                    // A course that can't be taken can't be assigned
+                    None => model.add_eq(LinearExpr::from(0), LinearExpr::from(1)),
+                };
             }
         }
-        // All required courses must count toward the Foundation
-        let mut required_sum = LinearExpr::from(0);
-        for &idx in &required_idxs {
-            let c = if *is_credits { ctx.courses[idx].credits as i64 } else { 1 };
-            for _ in 0..c {
-                required_sum = required_sum + course_in_schedule(idx);
+        GenEdReq::SetOpts(opts) => {
+            // At least one option must be fully assigned
+            let mut option_vars = Vec::new();
+            for opt in opts {
+                let Some(opt_assigned) = opt.iter().map(var_for).collect::<Option<Vec<_>>>() else { continue };
+                let opt_var = model.new_bool_var();
+                for var in opt_assigned {
+                    model.add_ge(var, opt_var);
+                }
+                option_vars.push(opt_var);
             }
-        }
-        // Optional courses can be used to reach the minimum, but not to over-satisfy
-        let mut optional_sum = LinearExpr::from(0);
-        for &idx in &optional_idxs {
-            let c = if *is_credits { ctx.courses[idx].credits as i64 } else { 1 };
-            for _ in 0..c {
-                optional_sum = optional_sum + course_in_schedule(idx);
+            if !opts.is_empty() {
+                let sum: LinearExpr = option_vars.into_iter().collect();
+                model.add_ge(sum, LinearExpr::from(1));
             }
         }
-        // The total must be at least the required minimum
-        model.add_ge(required_sum.clone() + optional_sum.clone(), LinearExpr::from(*required));
-        // The total (required + optional) cannot exceed the maximum of required_sum and required
-        // Set the domain to the true maximum possible sum (credits or count)
-        let max_possible = if *is_credits {
-            set.iter().map(|&idx| ctx.courses[idx].credits as i64).sum()
-        } else {
-            set.len() as i64
-        };
-        let max_expr = model.new_int_var([(0, max_possible)]);
-        model.add_ge(max_expr.clone(), required_sum.clone());
-        model.add_ge(max_expr.clone(), LinearExpr::from(*required));
-        model.add_le(required_sum.clone() + optional_sum.clone(), max_expr);
-    }
-
-    // Handle courses already present in the Schedule (forced courses)
-    for (set, _, _, _) in &foundation_reqs {
-        for &idx in set {
-            if ctx.courses[idx].required {
-                // Add constraints to ensure forced courses are considered for all eligible Foundations
-                let mut foundation_vars = Vec::new();
-                for (other_set, _, _, _) in &foundation_reqs {
-                    if other_set.contains(&idx) {
-                        let foundation_var = model.new_bool_var();
-                        model.add_le(foundation_var.clone(), course_in_schedule(idx));
-                        foundation_vars.push(foundation_var);
-                    }
-                }
-                let mut sum = LinearExpr::from(0);
-                for v in foundation_vars {
-                    sum = sum + LinearExpr::from(v);
-                }
-                model.add_eq(sum, LinearExpr::from(1)); // Ensure the forced course satisfies exactly one Foundation
-                // No restriction here for S&Ps: forced courses can also be used for S&Ps as long as S&P constraints are met
+        GenEdReq::Courses { num, courses } => {
+            let sum: LinearExpr = courses.iter().filter_map(var_for).collect();
+            model.add_ge(sum, LinearExpr::from(*num as i64));
+        }
+        GenEdReq::Credits { num, courses } => {
+            let mut sum = LinearExpr::from(0);
+            for code in courses {
+                if let (Some(&idx), Some(var)) = (code_to_idx.get(code), var_for(code)) { sum += credits_if(model, idx, var); }
             }
+            model.add_ge(sum, LinearExpr::from(*num as i64));
         }
-    }
-
-    // For every pair of Foundations, require that the number of scheduled courses in the intersection is at most the overlap allowed (usually zero)
-    for i in 0..num_foundations {
-        for j in (i+1)..num_foundations {
-            let set_i: HashSet<_> = foundation_reqs[i].0.iter().copied().collect();
-            let set_j: HashSet<_> = foundation_reqs[j].0.iter().copied().collect();
-            let intersection: Vec<_> = set_i.intersection(&set_j).copied().collect();
-            if !intersection.is_empty() {
-                let mut sum = LinearExpr::from(0);
-                for &idx in &intersection {
-                    sum = sum + course_in_schedule(idx);
-                }
-                // By default, require no overlap (can be relaxed if needed)
-                model.add_le(sum, LinearExpr::from(0));
+        GenEdReq::DistinctStems { num, req: inner } => {
+            // One bool var per stem, only true if some assigned course is from that stem
+            let mut by_stem: HashMap<&str, Vec<BoolVar>> = HashMap::new();
+            for code in inner.course_codes() {
+                if let Some(var) = var_for(code) { by_stem.entry(code.stem.as_str()).or_default().push(var); }
+            }
+            let mut stem_vars = Vec::new();
+            for vars in by_stem.into_values() {
+                let stem_var = model.new_bool_var();
+                let taken: LinearExpr = vars.into_iter().collect();
+                model.add_le(stem_var, taken);
+                stem_vars.push(stem_var);
             }
+            let sum: LinearExpr = stem_vars.into_iter().collect();
+            model.add_ge(sum, LinearExpr::from(*num as i64));
+            add_assigned_req(model, inner, assigned, code_to_idx, credits_if);
+        }
+        GenEdReq::IncludesLab { labs, req: inner } => {
+            let taken: LinearExpr = labs.iter().filter_map(var_for).collect();
+            model.add_ge(taken, LinearExpr::from(1));
+            add_assigned_req(model, inner, assigned, code_to_idx, credits_if);
         }
     }
+}
 
-    // The rest of the Foundation assignment logic (assignment matrix, etc.) can remain as before, but now feasibility is guaranteed by the above constraints.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geneds::validate_geneds;
+    use crate::model::two_stage_lex_schedule;
+    use crate::schedule::tests::{catalog, code, schedule};
+    use crate::schedule::CreditLimits;
+    use crate::validation::ValidationReport;
 
-    // --- Skills & Perspectives: no course may satisfy more than 3 S&Ps ---
-    let mut sp_sets = Vec::new();
-    for gened in geneds.iter() {
-        if let GenEd::SkillAndPerspective { req, .. } = gened {
-            match req {
-                GenEdReq::Set(codes) => {
-                    if let Some(indices) = codes_to_indices(codes) {
-                        sp_sets.push(indices.clone());
-                        // Split required/optional
-                        let mut required_idxs = Vec::new();
-                        let mut optional_idxs = Vec::new();
-                        for &idx in &indices {
-                            if ctx.courses[idx].required {
-                                required_idxs.push(idx);
-                            } else {
-                                optional_idxs.push(idx);
-                            }
-                        }
-                        let mut required_sum = LinearExpr::from(0);
-                        for &idx in &required_idxs {
-                            required_sum = required_sum + course_in_schedule(idx);
-                        }
-                        let mut optional_sum = LinearExpr::from(0);
-                        for &idx in &optional_idxs {
-                            optional_sum = optional_sum + course_in_schedule(idx);
-                        }
-                        let required = codes.len() as i64;
-                        // At least the minimum
-                        model.add_ge(required_sum.clone() + optional_sum.clone(), LinearExpr::from(required));
-                        // At most max(required_sum, required)
-                        let max_possible = required_idxs.len() as i64 + optional_idxs.len() as i64;
-                        let max_expr = model.new_int_var([(0, max_possible)]);
-                        model.add_ge(max_expr.clone(), required_sum.clone());
-                        model.add_ge(max_expr.clone(), LinearExpr::from(required));
-                        model.add_le(required_sum.clone() + optional_sum.clone(), max_expr);
-                    }
-                }
-                GenEdReq::Courses { num, courses } => {
-                    if let Some(indices) = codes_to_indices(courses) {
-                        sp_sets.push(indices.clone());
-                        let mut required_idxs = Vec::new();
-                        let mut optional_idxs = Vec::new();
-                        for &idx in &indices {
-                            if ctx.courses[idx].required {
-                                required_idxs.push(idx);
-                            } else {
-                                optional_idxs.push(idx);
-                            }
-                        }
-                        let mut required_sum = LinearExpr::from(0);
-                        for &idx in &required_idxs {
-                            required_sum = required_sum + course_in_schedule(idx);
-                        }
-                        let mut optional_sum = LinearExpr::from(0);
-                        for &idx in &optional_idxs {
-                            optional_sum = optional_sum + course_in_schedule(idx);
-                        }
-                        let required = *num as i64;
-                        model.add_ge(required_sum.clone() + optional_sum.clone(), LinearExpr::from(required));
-                        let max_possible = required_idxs.len() as i64 + optional_idxs.len() as i64;
-                        let max_expr = model.new_int_var([(0, max_possible)]);
-                        model.add_ge(max_expr.clone(), required_sum.clone());
-                        model.add_ge(max_expr.clone(), LinearExpr::from(required));
-                        model.add_le(required_sum.clone() + optional_sum.clone(), max_expr);
-                    }
-                }
-                GenEdReq::Credits { num, courses } => {
-                    if let Some(indices) = codes_to_indices(courses) {
-                        sp_sets.push(indices.clone());
-                        let mut required_sum = LinearExpr::from(0);
-                        let mut optional_sum = LinearExpr::from(0);
-                        for &idx in &indices {
-                            let credits = ctx.courses[idx].credits;
-                            if ctx.courses[idx].required {
-                                for _ in 0..credits {
-                                    required_sum = required_sum + course_in_schedule(idx);
-                                }
-                            } else {
-                                for _ in 0..credits {
-                                    optional_sum = optional_sum + course_in_schedule(idx);
-                                }
-                            }
-                        }
-                        let required = *num as i64;
-                        model.add_ge(required_sum.clone() + optional_sum.clone(), LinearExpr::from(required));
-                        let max_possible = indices.iter().map(|&idx| ctx.courses[idx].credits as i64).sum();
-                        let max_expr = model.new_int_var([(0, max_possible)]);
-                        model.add_ge(max_expr.clone(), required_sum.clone());
-                        model.add_ge(max_expr.clone(), LinearExpr::from(required));
-                        model.add_le(required_sum.clone() + optional_sum.clone(), max_expr);
-                    }
-                }
-                GenEdReq::SetOpts(opts) => {
-                    let mut all_indices = Vec::new();
-                    let mut option_exprs = Vec::new();
-                    for opt in opts {
-                        if let Some(indices) = codes_to_indices(opt) {
-                            all_indices.extend(&indices);
-                            let mut required_idxs = Vec::new();
-                            let mut optional_idxs = Vec::new();
-                            for &idx in &indices {
-                                if ctx.courses[idx].required {
-                                    required_idxs.push(idx);
-                                } else {
-                                    optional_idxs.push(idx);
-                                }
-                            }
-                            let mut required_sum = LinearExpr::from(0);
-                            for &idx in &required_idxs {
-                                required_sum = required_sum + course_in_schedule(idx);
-                            }
-                            let mut optional_sum = LinearExpr::from(0);
-                            for &idx in &optional_idxs {
-                                optional_sum = optional_sum + course_in_schedule(idx);
-                            }
-                            let opt_len = opt.len() as i64;
-                            let opt_var = model.new_bool_var();
-                            // Option is satisfied if all required+optional courses are present
-                            // model.add_le(required_sum.clone() + optional_sum.clone(), LinearExpr::from(opt_len) + (LinearExpr::from(1) - opt_var.clone()) * opt_len);
-                            // model.add_ge(required_sum.clone() + optional_sum.clone(), LinearExpr::from(opt_len) - (LinearExpr::from(1) - opt_var.clone()) * opt_len);
-                            let le_expr = LinearExpr::from(1) - opt_var.clone();
-                            let mut le_scaled = LinearExpr::from(opt_len);
-                            for _ in 0..opt_len {
-                                le_scaled = le_scaled + le_expr.clone();
-                            }
-                            model.add_le(required_sum.clone() + optional_sum.clone(), le_scaled);
-                            let mut ge_scaled = LinearExpr::from(opt_len);
-                            for _ in 0..opt_len {
-                                ge_scaled = ge_scaled - le_expr.clone();
-                            }
-                            model.add_ge(required_sum.clone() + optional_sum.clone(), ge_scaled);
-                            option_exprs.push(opt_var);
-                        }
-                    }
-                    if !option_exprs.is_empty() {
-                        let mut sum = LinearExpr::from(0);
-                        for v in option_exprs { sum = sum + LinearExpr::from(v); }
-                        model.add_ge(sum, LinearExpr::from(1));
-                    }
-                    sp_sets.push(all_indices);
-                }
+    /// Two Foundations that may not share a course, where only ENGL-2100 counts toward Literature.
+    fn english_foundations(plan: Vec<&str>) -> Schedule {
+        let catalog = catalog(serde_json::json!({
+            "geneds": [
+                {"Foundation": {"name": "Writing", "req": {"Courses": {"num": 1, "courses": ["ENGL-2100", "ENGL-1100"]}}}},
+                {"Foundation": {"name": "Literature", "req": {"Courses": {"num": 1, "courses": ["ENGL-2100"]}}}}
+            ],
+            "courses": {
+                "ENGL-1100": ["Composition", 3, "Both"],
+                "ENGL-2100": ["World Literature", 3, "Both"]
             }
-        }
+        }));
+        let mut sched = schedule(catalog, &[], vec![plan.into_iter().map(code).collect()]);
+        sched.credit_policy.default = CreditLimits { min: 0, max: 18 };
+        sched
     }
-    // For each course, count how many S&Ps it could satisfy
-    let mut course_sp_counts: std::collections::HashMap<usize, usize> = std::collections::HashMap::new();
-    for set in sp_sets.iter() {
-        for &idx in set {
-            *course_sp_counts.entry(idx).or_insert(0) += 1;
-        }
+
+    #[test]
+    fn assignment_finds_a_split_a_greedy_pick_would_miss() {
+        let sched = english_foundations(vec!["ENGL-1100", "ENGL-2100"]);
+        let geneds: Vec<&GenEd> = sched.catalog.geneds.iter().collect();
+        let (composition, literature) = (code("ENGL-1100"), code("ENGL-2100"));
+        // Writing lists ENGL-2100 first, but it has to go to Literature
+        let assigned = assign_geneds(&geneds, &[&literature, &composition], &sched).expect("both Foundations can be met");
+        assert_eq!(assigned[&composition], vec!["Writing".to_string()]);
+        assert_eq!(assigned[&literature], vec!["Literature".to_string()]);
+        // The Foundations can't both count the one course
+        assert!(assign_geneds(&geneds, &[&literature], &sched).is_none());
     }
-    // For each course, add constraint: sum of S&P assignments <= 3
-    for (&idx, &count) in &course_sp_counts {
-        if count > 3 {
-            // For each S&P, create a bool var if this course is used for that S&P
-            let mut used_vars = Vec::new();
-            for set in &sp_sets {
-                if set.contains(&idx) {
-                    let used = model.new_bool_var();
-                    model.add_le(used.clone(), course_in_schedule(idx));
-                    used_vars.push(used);
-                }
-            }
-            let mut sum = LinearExpr::from(0);
-            for v in used_vars { sum = sum + LinearExpr::from(v); }
-            model.add_le(sum, LinearExpr::from(3));
-        }
+
+    #[test]
+    fn validator_accepts_the_schedulers_assignment() {
+        let mut sched = english_foundations(vec![]);
+        two_stage_lex_schedule(&mut sched).unwrap();
+        let mut planned = sched.courses[0].clone();
+        planned.sort_by_key(|c| c.to_string());
+        assert_eq!(planned, vec![code("ENGL-1100"), code("ENGL-2100")]);
+        assert_eq!(sched.gened_assignments[&code("ENGL-1100")], vec!["Writing".to_string()]);
+        assert_eq!(sched.gened_assignments[&code("ENGL-2100")], vec!["Literature".to_string()]);
+
+        let mut report = ValidationReport::default();
+        validate_geneds(&sched, &mut report);
+        assert!(report.unmet_geneds.is_empty());
+        assert!(report.overlap_violations.is_empty());
     }
-    // No additional restriction: forced courses can be used for both a Foundation and S&Ps, as long as they are not double-counted for multiple Foundations or more than 3 S&Ps.
 }
@@ -2,8 +2,11 @@
 
 mod context;
 mod courses;
+mod diagnostics;
+mod electives;
 mod geneds;
 mod prereqs;
+mod programs;
 mod semester;
 mod two_stage_schedule;
 
@@ -13,4 +16,5 @@ use geneds::*;
 use prereqs::*;
 use semester::*;
 
+pub use geneds::assign_geneds;
 pub use two_stage_schedule::two_stage_lex_schedule;
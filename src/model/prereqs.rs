@@ -1,8 +1,9 @@
 //! Functions for adding prerequisite constraints.
-use super::context::{Course, ModelBuilderContext};
+use super::context::ModelBuilderContext;
+use crate::prereq_graph::PrereqGraph;
 use crate::prereqs::CourseReq;
 use crate::schedule::CourseCode;
-use cp_sat::builder::LinearExpr;
+use cp_sat::builder::{BoolVar, LinearExpr};
 use std::collections::HashMap;
 
 pub fn add_prereq_constraints<'a>(ctx: &mut ModelBuilderContext<'a>) {
@@ -12,121 +13,264 @@ pub fn add_prereq_constraints<'a>(ctx: &mut ModelBuilderContext<'a>) {
         .enumerate()
         .map(|(i, c)| (c.code.clone(), i))
         .collect();
+    // No course can come before the chain of prerequisites leading up to it; bounding that up
+    // front spares the solver from rediscovering it semester by semester
+    let schedule = ctx.schedule;
+    let min_sems = PrereqGraph::new(&schedule.catalog).min_semesters(&schedule.completed_courses());
     // Avoid borrow checker issues: collect prereqs first
     let prereqs: Vec<_> = ctx.courses.iter().map(|c| c.prereqs.clone()).collect();
     for (i, req) in prereqs.iter().enumerate() {
-        add_prereq_for_course(ctx, &idx_map, i, req);
+        let first_sem = min_sems
+            .get(&ctx.courses[i].code)
+            .map_or(0, |n| n.saturating_sub(1));
+        add_prereq_for_course(ctx, &idx_map, i, req, first_sem);
     }
 }
 
+/// Whether a leaf requirement is already met outright, by a completed course, assumed
+/// instructor consent, or the student's programs.
+fn met_outright(ctx: &ModelBuilderContext, course_idx: usize, req: &CourseReq) -> bool {
+    use crate::prereqs::CourseReq::*;
+    match req {
+        PreCourse(code) | CoCourse(code) => ctx.schedule.completes(code, None),
+        PreCourseGrade(code, grade) | CoCourseGrade(code, grade) => {
+            ctx.schedule.completes(code, Some(grade))
+        }
+        Instructor => ctx.instructor_consent.grants(&ctx.courses[course_idx].code),
+        Standing(standing) => ctx.completed_credits >= standing.min_credits() as i64,
+        Program(stem) => ctx.programs.iter().any(|p| p.assoc_stems.contains(stem)),
+        _ => false,
+    }
+}
+
+/// A requirement reified for one semester: known outright, or a literal that can only be
+/// true when the requirement holds.
+#[derive(Clone, Copy)]
+enum Reified {
+    True,
+    False,
+    Var(BoolVar),
+}
+
 fn add_prereq_for_course<'a>(
     ctx: &mut ModelBuilderContext<'a>,
     idx_map: &HashMap<CourseCode, usize>,
     course_idx: usize,
     req: &CourseReq,
+    first_sem: usize,
 ) {
+    for s in 0..ctx.num_semesters {
+        let cur = ctx.vars[course_idx][s];
+        if s < first_sem {
+            ctx.model.add_eq(cur, 0);
+            continue;
+        }
+        match reify_req(ctx, idx_map, course_idx, req, s) {
+            Reified::True => {}
+            Reified::False => {
+                ctx.model.add_eq(cur, 0);
+            }
+            Reified::Var(lit) => {
+                ctx.model.add_le(cur, lit);
+            }
+        }
+    }
+}
+
+/// Reify an arbitrary requirement tree for taking `course_idx` in semester `s`.
+fn reify_req<'a>(
+    ctx: &mut ModelBuilderContext<'a>,
+    idx_map: &HashMap<CourseCode, usize>,
+    course_idx: usize,
+    req: &CourseReq,
+    s: usize,
+) -> Reified {
     use crate::prereqs::CourseReq::*;
-    let num_semesters = ctx.num_semesters;
+    // Anything not met by the transcript (e.g. a grade below the threshold) needs a (re)take in the plan
+    if met_outright(ctx, course_idx, req) {
+        return Reified::True;
+    }
     match req {
-        NotRequired => {}
+        NotRequired => Reified::True,
         And(reqs) => {
+            let mut lits = Vec::new();
             for r in reqs {
-                add_prereq_for_course(ctx, idx_map, course_idx, r);
+                match reify_req(ctx, idx_map, course_idx, r, s) {
+                    Reified::True => {}
+                    Reified::False => return Reified::False,
+                    Reified::Var(lit) => lits.push(lit),
+                }
             }
-        }
-        Or(reqs) => {
-            for s in 0..num_semesters {
-                let cur = ctx.vars[course_idx][s];
-                let mut or_exprs = Vec::new();
-                for r in reqs {
-                    let or_var = ctx.model.new_bool_var();
-                    match r {
-                        PreCourse(code) => {
-                            if let Some(&pre_idx) = idx_map.get(code) {
-                                if s == 0 {
-                                } else {
-                                    let earlier_vars: Vec<_> =
-                                        ctx.vars[pre_idx][..s].iter().copied().collect();
-                                    if !earlier_vars.is_empty() {
-                                        let sum_earlier: LinearExpr =
-                                            earlier_vars.into_iter().collect();
-                                        ctx.model.add_linear_constraint(
-                                            sum_earlier - or_var,
-                                            [(0, i64::MAX)],
-                                        );
-                                        or_exprs.push(or_var);
-                                    }
-                                }
-                            }
-                        }
-                        CoCourse(code) => {
-                            if let Some(&co_idx) = idx_map.get(code) {
-                                let upto_vars: Vec<_> =
-                                    ctx.vars[co_idx][..=s].iter().copied().collect();
-                                if !upto_vars.is_empty() {
-                                    let sum_upto: LinearExpr = upto_vars.into_iter().collect();
-                                    ctx.model
-                                        .add_linear_constraint(sum_upto - or_var, [(0, i64::MAX)]);
-                                    or_exprs.push(or_var);
-                                }
-                            }
-                        }
-                        And(_) | Or(_) => {
-                            add_prereq_for_course(ctx, idx_map, course_idx, r);
-                        }
-                        _ => unimplemented!("Only PreCourse, CoCourse, And, Or supported"),
+            match lits.as_slice() {
+                [] => Reified::True,
+                [lit] => Reified::Var(*lit),
+                _ => {
+                    let and_var = ctx.model.new_bool_var();
+                    for lit in lits {
+                        ctx.model.add_le(and_var, lit);
                     }
-                    or_exprs.push(or_var);
-                }
-                if !or_exprs.is_empty() {
-                    let sum_or: LinearExpr = or_exprs.iter().copied().collect();
-                    ctx.model
-                        .add_linear_constraint(sum_or - cur, [(0, i64::MAX)]);
+                    Reified::Var(and_var)
                 }
             }
         }
-        PreCourse(code) => {
-            if let Some(&pre_idx) = idx_map.get(code) {
-                for s in 0..num_semesters {
-                    let cur = ctx.vars[course_idx][s];
-                    if s == 0 {
-                        ctx.model.add_eq(cur, 0);
-                    } else {
-                        let earlier_vars: Vec<_> = ctx.vars[pre_idx][..s].iter().copied().collect();
-                        if !earlier_vars.is_empty() {
-                            let sum_earlier: LinearExpr = earlier_vars.into_iter().collect();
-                            ctx.model
-                                .add_linear_constraint(sum_earlier - cur, [(0, i64::MAX)]);
-                        } else {
-                            ctx.model.add_eq(cur, 0);
-                        }
-                    }
+        Or(reqs) => {
+            let mut lits = Vec::new();
+            for r in reqs {
+                match reify_req(ctx, idx_map, course_idx, r, s) {
+                    Reified::True => return Reified::True,
+                    Reified::False => {}
+                    Reified::Var(lit) => lits.push(lit),
                 }
-            } else {
-                for s in 0..num_semesters {
-                    ctx.model.add_eq(ctx.vars[course_idx][s], 0);
+            }
+            match lits.as_slice() {
+                [] => Reified::False,
+                [lit] => Reified::Var(*lit),
+                _ => {
+                    let or_var = ctx.model.new_bool_var();
+                    let sum_or: LinearExpr = lits.into_iter().collect();
+                    ctx.model
+                        .add_linear_constraint(sum_or - or_var, [(0, i64::MAX)]);
+                    Reified::Var(or_var)
                 }
             }
         }
-        CoCourse(code) => {
-            if let Some(&co_idx) = idx_map.get(code) {
-                for s in 0..num_semesters {
-                    let cur = ctx.vars[course_idx][s];
-                    let upto_vars: Vec<_> = ctx.vars[co_idx][..=s].iter().copied().collect();
-                    if !upto_vars.is_empty() {
-                        let sum_upto: LinearExpr = upto_vars.into_iter().collect();
-                        ctx.model
-                            .add_linear_constraint(sum_upto - cur, [(0, i64::MAX)]);
-                    } else {
-                        ctx.model.add_eq(cur, 0);
-                    }
-                }
-            } else {
-                for s in 0..num_semesters {
-                    ctx.model.add_eq(ctx.vars[course_idx][s], 0);
-                }
+        PreCourse(code) | PreCourseGrade(code, _) => match idx_map.get(code) {
+            Some(&pre_idx) if s > 0 => {
+                let lit = ctx.model.new_bool_var();
+                let sum_earlier: LinearExpr = ctx.vars[pre_idx][..s].iter().copied().collect();
+                ctx.model
+                    .add_linear_constraint(sum_earlier - lit, [(0, i64::MAX)]);
+                Reified::Var(lit)
             }
+            _ => Reified::False,
+        },
+        CoCourse(code) | CoCourseGrade(code, _) => match idx_map.get(code) {
+            Some(&co_idx) => {
+                let lit = ctx.model.new_bool_var();
+                let sum_upto: LinearExpr = ctx.vars[co_idx][..=s].iter().copied().collect();
+                ctx.model
+                    .add_linear_constraint(sum_upto - lit, [(0, i64::MAX)]);
+                Reified::Var(lit)
+            }
+            None => Reified::False,
+        },
+        Standing(standing) => {
+            // Taking the course in semester s requires min credits earned before s
+            let min = standing.min_credits() as i64;
+            let lit = ctx.model.new_bool_var();
+            ctx.model.add_linear_constraint(
+                ctx.credits_before_expr(s) - LinearExpr::from((min, lit)),
+                [(0, i64::MAX)],
+            );
+            Reified::Var(lit)
         }
-        _ => unimplemented!("Only PreCourse, CoCourse, And, Or supported"),
+        // Not associated with any of the student's programs (otherwise met outright)
+        Program(_) => Reified::False,
+        // Consent not granted under this run's policy
+        Instructor => Reified::False,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GR;
+    use crate::model::build_model_pipeline;
+    use crate::prereqs::{Grade, GradeLetter, GradeQualifier, InstructorConsent};
+    use crate::schedule::Schedule;
+    use crate::schedule::tests::{catalog, code, schedule};
+
+    /// A chemistry major planning CHEM-1200 and then the research course CHEM-4950.
+    fn research_plan(consent: InstructorConsent) -> Schedule {
+        let catalog = catalog(serde_json::json!({
+            "programs": [
+                {"name": "BS Chemistry", "kind": "Major", "assoc_stems": ["CHEM"],
+                 "semesters": [["CHEM-1200"], ["CHEM-4950"]]}
+            ],
+            "courses": {
+                "CHEM-1200": ["General Chemistry I", 4, "Both"],
+                "CHEM-4950": ["Undergraduate Research", 3, "Both"]
+            }
+        }));
+        let mut sched = schedule(
+            catalog,
+            &["BS Chemistry"],
+            vec![vec![code("CHEM-1200")], vec![code("CHEM-4950")]],
+        );
+        sched.instructor_consent = consent;
+        sched
+    }
+
+    /// Reify `req` for taking CHEM-4950 in semester `s` of `sched`.
+    fn reify(sched: &Schedule, req: &CourseReq, s: usize) -> Reified {
+        let mut ctx = ModelBuilderContext::new(sched);
+        build_model_pipeline(&mut ctx);
+        let idx_map: HashMap<_, _> = ctx
+            .courses
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.code.clone(), i))
+            .collect();
+        let course_idx = idx_map[&code("CHEM-4950")];
+        reify_req(&mut ctx, &idx_map, course_idx, req, s)
+    }
+
+    #[test]
+    fn instructor_consent_follows_the_run_policy() {
+        let req = CourseReq::Instructor;
+        let never = research_plan(InstructorConsent::Never);
+        assert!(matches!(reify(&never, &req, 1), Reified::False));
+        let always = research_plan(InstructorConsent::Always);
+        assert!(matches!(reify(&always, &req, 1), Reified::True));
+        let listed = research_plan(InstructorConsent::Courses(vec![code("CHEM-4950")]));
+        assert!(matches!(reify(&listed, &req, 1), Reified::True));
+        let others = research_plan(InstructorConsent::Courses(vec![code("CHEM-1200")]));
+        assert!(matches!(reify(&others, &req, 1), Reified::False));
+
+        // Without consent, an alternative prerequisite still allows the course
+        let or = CourseReq::Or(vec![
+            CourseReq::Instructor,
+            CourseReq::PreCourse(code("CHEM-1200")),
+        ]);
+        assert!(matches!(reify(&never, &or, 1), Reified::Var(_)));
+        assert!(matches!(reify(&never, &or, 0), Reified::False));
+    }
+
+    #[test]
+    fn any_requirement_tree_is_reified() {
+        use CourseReq::*;
+        let sched = research_plan(InstructorConsent::Never);
+        let chem_1200 = || PreCourse(code("CHEM-1200"));
+        let stem = |stem: &str| Program(stem.to_string());
+        // Program requirements resolve through the student's programs' associated stems
+        assert!(matches!(reify(&sched, &stem("CHEM"), 1), Reified::True));
+        assert!(matches!(reify(&sched, &stem("PHYS"), 1), Reified::False));
+        // An unmet And inside an Or only rules out its own branch
+        let nested = Or(vec![And(vec![chem_1200(), stem("PHYS")]), chem_1200()]);
+        assert!(matches!(reify(&sched, &nested, 1), Reified::Var(_)));
+        let met = Or(vec![And(vec![chem_1200(), stem("PHYS")]), stem("CHEM")]);
+        assert!(matches!(reify(&sched, &met, 1), Reified::True));
+        let unmet = And(vec![Or(vec![stem("PHYS"), Instructor]), chem_1200()]);
+        assert!(matches!(reify(&sched, &unmet, 1), Reified::False));
+    }
+
+    #[test]
+    fn graded_requirements_met_by_the_transcript_need_no_retake() {
+        use CourseReq::*;
+        let mut sched = research_plan(InstructorConsent::Never);
+        sched.transcript.grades.insert(code("CHEM-1200"), GR!(B));
+        let graded = |grade| {
+            Or(vec![
+                Program("PHYS".to_string()),
+                PreCourseGrade(code("CHEM-1200"), grade),
+            ])
+        };
+        assert!(matches!(reify(&sched, &graded(GR!(C)), 0), Reified::True));
+        // A B falls short of an A-, so CHEM-1200 must be retaken first
+        assert!(matches!(
+            reify(&sched, &graded(GR!(A-)), 1),
+            Reified::Var(_)
+        ));
+        assert!(matches!(reify(&sched, &graded(GR!(A-)), 0), Reified::False));
     }
 }
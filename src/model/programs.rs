@@ -0,0 +1,75 @@
+//! Functions for assigning courses to the programs they serve and limiting the credits programs share.
+use super::context::{ModelBuilderContext, ProgramAssignment};
+use super::diagnostics::Requirement;
+use crate::schedule::CourseCode;
+use cp_sat::builder::LinearExpr;
+use std::collections::{HashMap, HashSet};
+
+/// Add a var for each course that may serve each program, and keep every pair of programs within
+/// their shared-credit limits. Required courses always serve their program; elective options
+/// serve it only when the solver counts them there. Programs whose common required courses
+/// exceed their limit can't be met, and diagnostics report them as such.
+pub fn add_program_constraints<'a>(ctx: &mut ModelBuilderContext<'a>) {
+    let idx_map: HashMap<_, _> = ctx
+        .courses
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.code.clone(), i))
+        .collect();
+    let programs = ctx.programs.clone();
+    let mut assignments = Vec::new();
+    for program in &programs {
+        let required: HashSet<&CourseCode> = program.required_courses().collect();
+        let electives = program.electives.iter().flat_map(|e| e.all_course_codes());
+        let mut seen = HashSet::new();
+        for code in required.iter().map(|c| (*c).clone()).chain(electives) {
+            let Some(&idx) = idx_map.get(&code) else {
+                continue;
+            };
+            if !seen.insert(idx) {
+                continue;
+            }
+            let var = ctx.model.new_bool_var();
+            let taken = ctx.course_taken_expr(idx);
+            if required.contains(&code) {
+                ctx.model.add_eq(var, taken);
+            } else {
+                ctx.model.add_le(var, taken);
+            }
+            assignments.push(ProgramAssignment {
+                program: program.name.as_str(),
+                course: idx,
+                var,
+            });
+        }
+    }
+
+    if ctx.enforces(&Requirement::SharedCredits) {
+        for (i, program) in programs.iter().enumerate() {
+            for other in &programs[i + 1..] {
+                let Some(max) = [program.max_shared_credits, other.max_shared_credits]
+                    .into_iter()
+                    .flatten()
+                    .min()
+                else {
+                    continue;
+                };
+                // A course shares its credits when it serves both programs
+                let mut shared = LinearExpr::from(0);
+                for a in assignments.iter().filter(|a| a.program == program.name) {
+                    let both_serve = assignments
+                        .iter()
+                        .find(|b| b.program == other.name && b.course == a.course);
+                    if let Some(b) = both_serve {
+                        let both = ctx.model.new_bool_var();
+                        ctx.model
+                            .add_ge(LinearExpr::from(both) + 1, LinearExpr::from(a.var) + b.var);
+                        shared += ctx.credits_if(a.course, both);
+                    }
+                }
+                ctx.model.add_le(shared, max as i64);
+            }
+        }
+    }
+    ctx.program_assignments = assignments;
+}
@@ -1,16 +1,44 @@
-/// Functions for adding generic semester constraints (e.g., max credits).
+/// Functions for adding generic semester constraints (e.g., min/max credits).
 use super::context::ModelBuilderContext;
+use super::diagnostics::Requirement;
 
 pub fn add_semester_constraints<'a>(ctx: &mut ModelBuilderContext<'a>) {
     // For each semester, sum the credits of all courses scheduled and add a constraint
     for s in 0..ctx.num_semesters {
-        let weighted_terms: Vec<(i64, _)> = ctx
-            .courses
-            .iter()
-            .enumerate()
-            .map(|(i, c)| (c.credits, ctx.vars[i][s]))
-            .collect();
-        let weighted_sum: cp_sat::builder::LinearExpr = weighted_terms.into_iter().collect();
-        ctx.model.add_le(weighted_sum, ctx.max_credits_per_semester);
+        let mut weighted_sum = cp_sat::builder::LinearExpr::from(0);
+        for i in 0..ctx.courses.len() {
+            weighted_sum += ctx.semester_credits_expr(i, s);
+        }
+        let limits = ctx.credit_limits[s];
+        ctx.model.add_le(weighted_sum.clone(), limits.max as i64);
+        if ctx.enforces(&Requirement::CreditMinimum) {
+            ctx.model.add_ge(weighted_sum, limits.min as i64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::tests::{catalog, schedule};
+    use crate::schedule::{CreditLimits, CreditPolicy};
+    use crate::terms::{Season, Term};
+
+    #[test]
+    fn planned_semesters_take_their_own_credit_limits() {
+        let light = CreditLimits { min: 6, max: 12 };
+        let summer = CreditLimits { min: 0, max: 7 };
+        let mut sched = schedule(catalog(serde_json::json!({})), &[], vec![vec![]; 3]);
+        sched.terms[2] = Term::new(Season::Summer, 2026);
+        sched.locked_semesters = 1;
+        sched.credit_policy = CreditPolicy {
+            semesters: [(1, light)].into(),
+            summer: Some(summer),
+            ..Default::default()
+        };
+        // Locked semesters aren't modeled, so the limits start at the first planned one
+        let ctx = ModelBuilderContext::new(&sched);
+        assert_eq!(ctx.credit_limits, vec![light, summer]);
+        assert_eq!(ctx.max_credits_per_semester(), 12);
     }
 }
@@ -1,21 +1,34 @@
+use super::diagnostics::diagnose_infeasibility;
 use super::{ModelBuilderContext, build_model_pipeline};
 use crate::schedule::{CourseCode, Schedule};
 use anyhow::{Result, anyhow};
-use cp_sat::builder::{CpModelBuilder, IntVar, LinearExpr};
+use cp_sat::builder::LinearExpr;
 use cp_sat::proto::CpSolverStatus;
+use std::collections::HashMap;
 
 /// Returns Some(Vec<Vec<(CourseCode, i64)>>) if a feasible schedule is found, else None.
-pub fn two_stage_lex_schedule(sched: &mut Schedule, max_credits_per_semester: i64) -> Result<()> {
+///
+/// Stages run lexicographically: first the fewest courses on uncertain (Discretion/Infrequently)
+/// offerings that a reliable course could replace, then the fewest total credits, then the most
+/// balanced and ordered semesters.
+pub fn two_stage_lex_schedule(sched: &mut Schedule) -> Result<()> {
     let mut params = cp_sat::proto::SatParameters::default();
     params.log_search_progress = Some(false);
     params.num_search_workers = Some(8);
-    // Stage 1: minimize total credits
-    let mut ctx = ModelBuilderContext::new(sched, max_credits_per_semester);
+    // Stage 1: minimize placements on uncertain offerings, then total credits. Weighting each
+    // placement above any credit total lets a reliable option win even when it costs more
+    // credits, without a separate solve
+    let mut ctx = ModelBuilderContext::new(sched);
     let (mut model, vars, flat_courses) = build_model_pipeline(&mut ctx);
-    let num_semesters = sched.courses.len();
-    let total_credits = ctx.total_credits_expr(&vars, &flat_courses);
-    model.minimize(total_credits.clone());
+    // Only the semesters after the locked history are planned
+    let num_semesters = ctx.num_semesters;
+    if num_semesters == 0 {
+        return Ok(());
+    }
+    let at_risk_weight = ctx.credits_bound();
+    model.minimize(ctx.at_risk_expr(at_risk_weight) + ctx.total_credits_expr());
     let response = model.solve_with_parameters(&params);
+    let min_at_risk = response.objective_value.round() as i64 / at_risk_weight;
 
     // Compute min_credits as the sum of all scheduled (assigned + prereq) course credits in the solution
     let min_credits = match response.status() {
@@ -26,41 +39,38 @@ pub fn two_stage_lex_schedule(sched: &mut Schedule, max_credits_per_semester: i6
                     if vars[i][s].solution_value(&response) {
                         total += credits;
                     }
+                    if let Some(extra) = &ctx.extra_credits[i] {
+                        total += extra[s].solution_value(&response);
+                    }
                 }
             }
             total
         }
         _ => {
-            // No feasible solution
-            return Err(anyhow!(
-                "No feasible solution found in single-stage scheduling"
-            ));
+            // No feasible solution: explain which requirements conflict
+            return Err(diagnose_infeasibility(sched).into());
         }
     };
 
     // Stage 2: minimize spread, subject to min total credits
-    let mut ctx2 = ModelBuilderContext::new(sched, max_credits_per_semester);
+    let mut ctx2 = ModelBuilderContext::new(sched);
+    ctx2.max_at_risk = Some(min_at_risk);
     ctx2.set_min_credits(min_credits);
     let (mut model2, vars2, flat_courses2) = build_model_pipeline(&mut ctx2);
+    let max_credits_per_semester = ctx2.max_credits_per_semester();
+    // Balance and ordering only consider Fall/Spring; optional sessions are used as needed
+    let regular: Vec<usize> = (0..num_semesters)
+        .filter(|&s| ctx2.terms[s].is_regular())
+        .collect();
     // Compute mean load (rounded down)
-    let mean_load = min_credits / num_semesters as i64;
+    let mean_load = min_credits / regular.len().max(1) as i64;
 
     // For each semester, create an IntVar for the semester's total credits
     let mut semester_credit_vars = Vec::new();
     for s in 0..num_semesters {
         let mut expr = LinearExpr::from(0);
         for i in 0..flat_courses2.len() {
-            let var = vars2[i][s].clone();
-            let coeff = flat_courses2[i].1;
-            if coeff > 0 {
-                for _ in 0..coeff {
-                    expr = expr + LinearExpr::from(var.clone());
-                }
-            } else if coeff < 0 {
-                for _ in 0..(-coeff) {
-                    expr = expr - LinearExpr::from(var.clone());
-                }
-            }
+            expr += ctx2.semester_credits_expr(i, s);
         }
         // Domain: [0, max_credits_per_semester * flat_courses2.len() as i64]
         let domain = vec![(0, max_credits_per_semester * flat_courses2.len() as i64)];
@@ -71,7 +81,7 @@ pub fn two_stage_lex_schedule(sched: &mut Schedule, max_credits_per_semester: i6
 
     // For each semester, create an IntVar for the absolute deviation from mean
     let mut abs_deviation_vars = Vec::new();
-    for (_s, credit_var) in semester_credit_vars.iter().enumerate() {
+    for credit_var in regular.iter().map(|&s| &semester_credit_vars[s]) {
         let diff_domain = vec![(
             -max_credits_per_semester * flat_courses2.len() as i64,
             max_credits_per_semester * flat_courses2.len() as i64,
@@ -127,9 +137,10 @@ pub fn two_stage_lex_schedule(sched: &mut Schedule, max_credits_per_semester: i6
         count_courses.push(count);
     }
     let mut order_penalty = LinearExpr::from(0);
-    for s in 0..(num_semesters - 1) {
-        // Penalize if sum in s > sum in s+1 (approximate ascending order)
-        let diff = sum_codes[s].clone() - sum_codes[s + 1].clone();
+    for pair in regular.windows(2) {
+        let (s, next) = (pair[0], pair[1]);
+        // Penalize if sum in s > sum in next (approximate ascending order)
+        let diff = sum_codes[s].clone() - sum_codes[next].clone();
         // Only penalize positive differences
         let diff_var = model2.new_int_var(vec![(0, 1000000)]);
         model2.add_ge(diff_var.clone(), diff);
@@ -148,18 +159,58 @@ pub fn two_stage_lex_schedule(sched: &mut Schedule, max_credits_per_semester: i6
         CpSolverStatus::Optimal | CpSolverStatus::Feasible => {
             // Build the schedule output: Vec<Vec<(CourseCode, i64)>>
             let mut result = vec![vec![]; num_semesters];
+            let mut chosen_credits = HashMap::new();
             for (i, (course, credits)) in flat_courses2.iter().enumerate() {
                 for s in 0..num_semesters {
                     if vars2[i][s].solution_value(&response2) {
-                        result[s].push((course.code.clone(), *credits));
+                        let mut credits = *credits;
+                        if let Some(extra) = &ctx2.extra_credits[i] {
+                            credits += extra[s].solution_value(&response2);
+                            chosen_credits.insert(course.code.clone(), credits as u32);
+                        }
+                        result[s].push((course.code.clone(), credits));
                     }
                 }
             }
-            // Overwrite sched.courses with the new schedule (just the codes)
-            sched.courses = result
+            // Record which geneds each course was counted toward
+            let mut gened_assignments: HashMap<CourseCode, Vec<String>> = HashMap::new();
+            for assignment in &ctx2.gened_assignments {
+                if assignment.var.solution_value(&response2) {
+                    gened_assignments
+                        .entry(flat_courses2[assignment.course].0.code.clone())
+                        .or_default()
+                        .push(assignment.gened.to_string());
+                }
+            }
+            // Record which programs each course serves
+            let mut program_assignments: HashMap<CourseCode, Vec<String>> = HashMap::new();
+            for assignment in &ctx2.program_assignments {
+                if assignment.var.solution_value(&response2) {
+                    program_assignments
+                        .entry(flat_courses2[assignment.course].0.code.clone())
+                        .or_default()
+                        .push(assignment.program.to_string());
+                }
+            }
+            sched.gened_assignments = gened_assignments;
+            sched.program_assignments = program_assignments;
+            // Completed courses keep the credits they were taken for
+            let completed = sched.completed_courses();
+            let kept: HashMap<CourseCode, u32> = sched
+                .chosen_credits
                 .iter()
-                .map(|sem| sem.iter().map(|(code, _)| code.clone()).collect())
+                .filter(|(code, _)| completed.contains(code))
+                .map(|(code, cr)| (code.clone(), *cr))
                 .collect();
+            sched.chosen_credits = kept;
+            sched.chosen_credits.extend(chosen_credits);
+            // Overwrite the planned part of sched.courses with the new schedule (just the codes)
+            sched.courses.truncate(sched.locked_semesters);
+            sched.courses.extend(
+                result
+                    .iter()
+                    .map(|sem| sem.iter().map(|(code, _)| code.clone()).collect::<Vec<_>>()),
+            );
             Ok(())
         }
         _ => Err(anyhow!(
@@ -0,0 +1,319 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::prereqs::CourseReq;
+use crate::schedule::{Catalog, CourseCode};
+
+/// The catalog's prerequisites as a graph of courses. `PreCourse` edges order courses across
+/// semesters, while `CoCourse` edges may be met in the same semester, so a lab and its lecture
+/// may require each other but two courses may not each have to come first.
+pub struct PrereqGraph<'a> {
+    catalog: &'a Catalog,
+    /// Courses that must come before each course however its prerequisites are met
+    required_pre: HashMap<&'a CourseCode, Vec<&'a CourseCode>>,
+}
+
+impl<'a> PrereqGraph<'a> {
+    pub fn new(catalog: &'a Catalog) -> Self {
+        let required_pre = catalog
+            .prereqs
+            .iter()
+            .map(|(code, req)| {
+                let mut pre: Vec<&CourseCode> = required_pre_courses(req).into_iter().collect();
+                pre.sort_by_key(|c| c.to_string());
+                (code, pre)
+            })
+            .collect();
+        PrereqGraph {
+            catalog,
+            required_pre,
+        }
+    }
+
+    /// Courses that are, through their required prerequisites, prerequisites of themselves.
+    /// At least one cycle is reported for every group of courses that can't be ordered.
+    pub fn cycles(&self) -> Vec<Vec<CourseCode>> {
+        // Depth-first search; every edge back into the current path closes a cycle
+        fn visit<'a>(
+            code: &'a CourseCode,
+            edges: &HashMap<&'a CourseCode, Vec<&'a CourseCode>>,
+            path: &mut Vec<&'a CourseCode>,
+            done: &mut HashSet<&'a CourseCode>,
+            cycles: &mut Vec<Vec<CourseCode>>,
+        ) {
+            if let Some(pos) = path.iter().position(|c| *c == code) {
+                cycles.push(path[pos..].iter().map(|c| (*c).clone()).collect());
+                return;
+            }
+            if done.contains(code) {
+                return;
+            }
+            path.push(code);
+            for next in edges.get(code).into_iter().flatten() {
+                visit(next, edges, path, done, cycles);
+            }
+            path.pop();
+            done.insert(code);
+        }
+
+        let mut starts: Vec<&CourseCode> = self.required_pre.keys().copied().collect();
+        starts.sort_by_key(|c| c.to_string());
+        let mut cycles = vec![];
+        let mut done = HashSet::new();
+        for code in starts {
+            visit(
+                code,
+                &self.required_pre,
+                &mut vec![],
+                &mut done,
+                &mut cycles,
+            );
+        }
+        cycles
+    }
+
+    /// The fewest semesters needed to reach and take each course, counting the course's own
+    /// semester and ignoring term offerings. `completed` courses need none. Courses that can
+    /// never be reached, e.g. because of a cycle, are left out.
+    pub fn min_semesters(
+        &self,
+        completed: &HashSet<&CourseCode>,
+    ) -> HashMap<&'a CourseCode, usize> {
+        let codes: HashSet<&'a CourseCode> = self
+            .catalog
+            .courses
+            .keys()
+            .chain(self.catalog.prereqs.keys())
+            .collect();
+        // Start every course at its lowest possible count and raise counts until they settle;
+        // a count past the number of courses can only come from a cycle
+        let mut sems: HashMap<&'a CourseCode, Option<usize>> = codes
+            .iter()
+            .map(|code| (*code, Some(if completed.contains(code) { 0 } else { 1 })))
+            .collect();
+        loop {
+            let mut changed = false;
+            for code in &codes {
+                if completed.contains(code) || sems[code].is_none() {
+                    continue;
+                }
+                let Some(req) = self.catalog.prereqs.get(*code) else {
+                    continue;
+                };
+                let sems_of = |c: &CourseCode| sems.get(c).copied().unwrap_or(Some(1));
+                let need = semesters_for(req, &sems_of).filter(|&n| n <= codes.len());
+                if need != sems[code] {
+                    sems.insert(code, need);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        sems.into_iter()
+            .filter_map(|(code, n)| Some((code, n?)))
+            .collect()
+    }
+
+    /// The chain of prerequisites that decides how soon `code` can be taken, ending with `code`.
+    pub fn critical_path(
+        &self,
+        code: &'a CourseCode,
+        sems: &HashMap<&'a CourseCode, usize>,
+    ) -> Vec<&'a CourseCode> {
+        let sems_of = |c: &CourseCode| match sems.get(c) {
+            Some(&n) => Some(n),
+            None if self.catalog.courses.contains_key(c)
+                || self.catalog.prereqs.contains_key(c) =>
+            {
+                None
+            }
+            None => Some(1),
+        };
+        let mut path = vec![code];
+        let mut seen = HashSet::from([code]);
+        let mut cur = code;
+        while let Some(req) = self.catalog.prereqs.get(cur) {
+            match binding_course(req, &sems_of) {
+                Some(next) if seen.insert(next) => {
+                    path.push(next);
+                    cur = next;
+                }
+                _ => break,
+            }
+        }
+        path.reverse();
+        path
+    }
+
+    /// For each program, the critical path to its capstone: the required course that takes the
+    /// most semesters to reach.
+    pub fn program_critical_paths(&self) -> Vec<(String, Vec<&'a CourseCode>)> {
+        let sems = self.min_semesters(&HashSet::new());
+        self.catalog
+            .programs
+            .iter()
+            .map(|program| {
+                // Later courses win ties, so the capstone is the last of the deepest courses
+                let capstone = program
+                    .required_courses()
+                    .filter(|code| sems.contains_key(code))
+                    .max_by_key(|code| sems[code]);
+                let path = match capstone {
+                    Some(code) => self.critical_path(code, &sems),
+                    None => vec![],
+                };
+                (program.name.clone(), path)
+            })
+            .collect()
+    }
+}
+
+/// Semesters needed to take a course with `req`, given the semesters `sems` says each course
+/// needs (`None` for courses that can't be reached).
+fn semesters_for(req: &CourseReq, sems: &dyn Fn(&CourseCode) -> Option<usize>) -> Option<usize> {
+    match req {
+        CourseReq::And(reqs) => reqs
+            .iter()
+            .try_fold(1, |acc, r| Some(acc.max(semesters_for(r, sems)?))),
+        CourseReq::Or(reqs) => reqs.iter().filter_map(|r| semesters_for(r, sems)).min(),
+        CourseReq::PreCourse(code) | CourseReq::PreCourseGrade(code, _) => Some(sems(code)? + 1),
+        CourseReq::CoCourse(code) | CourseReq::CoCourseGrade(code, _) => Some(sems(code)?.max(1)),
+        CourseReq::Program(_)
+        | CourseReq::Standing(_)
+        | CourseReq::Instructor
+        | CourseReq::NotRequired => Some(1),
+    }
+}
+
+/// The course in `req` that decides how many semesters it needs, if any. On a tie, courses
+/// that must come first win over co-requisites, which often lead straight back to the course.
+fn binding_course<'r>(
+    req: &'r CourseReq,
+    sems: &dyn Fn(&CourseCode) -> Option<usize>,
+) -> Option<&'r CourseCode> {
+    match req {
+        CourseReq::And(reqs) => reqs
+            .iter()
+            .filter(|r| semesters_for(r, sems).is_some_and(|n| n > 1))
+            .max_by_key(|r| {
+                let concurrent =
+                    matches!(r, CourseReq::CoCourse(_) | CourseReq::CoCourseGrade(_, _));
+                (semesters_for(r, sems), !concurrent)
+            })
+            .and_then(|r| binding_course(r, sems)),
+        CourseReq::Or(reqs) => reqs
+            .iter()
+            .filter(|r| semesters_for(r, sems).is_some())
+            .min_by_key(|r| semesters_for(r, sems))
+            .and_then(|r| binding_course(r, sems)),
+        CourseReq::PreCourse(code)
+        | CourseReq::PreCourseGrade(code, _)
+        | CourseReq::CoCourse(code)
+        | CourseReq::CoCourseGrade(code, _) => {
+            sems(code).filter(|&n| n > 0)?;
+            Some(code)
+        }
+        _ => None,
+    }
+}
+
+/// Courses that must be taken beforehand however `req` is satisfied.
+fn required_pre_courses(req: &CourseReq) -> HashSet<&CourseCode> {
+    match req {
+        CourseReq::And(reqs) => reqs.iter().flat_map(required_pre_courses).collect(),
+        CourseReq::Or(reqs) => {
+            let mut sets = reqs.iter().map(required_pre_courses);
+            let first = sets.next().unwrap_or_default();
+            sets.fold(first, |acc, set| acc.intersection(&set).copied().collect())
+        }
+        CourseReq::PreCourse(code) | CourseReq::PreCourseGrade(code, _) => HashSet::from([code]),
+        _ => HashSet::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::tests::{catalog, code};
+    use serde_json::json;
+
+    /// Calculus, then physics with its lab, then modern physics.
+    fn physics() -> Catalog {
+        catalog(json!({
+            "programs": [{"name": "BS Physics", "kind": "Major",
+                          "semesters": [["MATH-1300"], ["PHYS-2100", "PHYS-2101"], ["PHYS-3000"]]}],
+            "prereqs": {
+                "PHYS-2100": {"And": [{"PreCourse": "MATH-1300"}, {"CoCourse": "PHYS-2101"}]},
+                "PHYS-2101": {"CoCourse": "PHYS-2100"},
+                "PHYS-3000": {"Or": [{"PreCourse": "PHYS-2100"}, {"PreCourse": "PHYS-3500"}]},
+                "PHYS-3500": {"PreCourse": "PHYS-3000"}
+            },
+            "courses": {
+                "MATH-1300": ["Calculus I", 4, "Both"],
+                "PHYS-2100": ["University Physics I", 4, "Both"],
+                "PHYS-2101": ["University Physics I Lab", 1, "Both"],
+                "PHYS-3000": ["Modern Physics", 3, "Both"],
+                "PHYS-3500": ["Quantum Mechanics", 3, "Both"]
+            }
+        }))
+    }
+
+    #[test]
+    fn co_requisites_and_alternatives_are_not_cycles() {
+        assert!(PrereqGraph::new(&physics()).cycles().is_empty());
+    }
+
+    #[test]
+    fn required_prerequisites_can_form_cycles() {
+        let catalog = catalog(json!({
+            "prereqs": {
+                "PHYS-3000": {"PreCourse": "PHYS-3500"},
+                "PHYS-3500": {"And": [{"PreCourse": "PHYS-3000"}, {"CoCourse": "MATH-1300"}]}
+            },
+            "courses": {"MATH-1300": ["Calculus I", 4, "Both"]}
+        }));
+        let graph = PrereqGraph::new(&catalog);
+        assert_eq!(
+            graph.cycles(),
+            vec![vec![code("PHYS-3000"), code("PHYS-3500")]]
+        );
+        // Neither course can ever be reached
+        let sems = graph.min_semesters(&HashSet::new());
+        assert!(!sems.contains_key(&code("PHYS-3000")));
+        assert_eq!(sems[&code("MATH-1300")], 1);
+    }
+
+    #[test]
+    fn depth_counts_semesters_along_the_chain() {
+        let catalog = physics();
+        let graph = PrereqGraph::new(&catalog);
+        let sems = graph.min_semesters(&HashSet::new());
+        let depth = |s: &str| sems[&code(s)];
+        assert_eq!(
+            [
+                "MATH-1300",
+                "PHYS-2100",
+                "PHYS-2101",
+                "PHYS-3000",
+                "PHYS-3500"
+            ]
+            .map(depth),
+            [1, 2, 2, 3, 4]
+        );
+
+        let math = code("MATH-1300");
+        let sems = graph.min_semesters(&HashSet::from([&math]));
+        assert_eq!(sems[&math], 0);
+        assert_eq!(sems[&code("PHYS-3000")], 2);
+    }
+
+    #[test]
+    fn critical_path_prefers_prerequisites_over_co_requisites() {
+        let catalog = physics();
+        let paths = PrereqGraph::new(&catalog).program_critical_paths();
+        let path: Vec<String> = paths[0].1.iter().map(|c| c.to_string()).collect();
+        assert_eq!(paths[0].0, "BS Physics");
+        assert_eq!(path, ["MATH-1300", "PHYS-2100", "PHYS-3000"]);
+    }
+}
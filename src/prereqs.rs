@@ -1,7 +1,9 @@
 use savefile_derive::Savefile;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 
-use crate::schedule::{CourseCode, Schedule};
+use crate::schedule::{CourseCode, Schedule, Semester};
 
 #[derive(Savefile, Serialize, Deserialize, Debug, Default, Hash, Clone, PartialEq, Eq)]
 pub enum CourseReq {
@@ -12,12 +14,66 @@ pub enum CourseReq {
     PreCourseGrade(CourseCode, Grade),
     CoCourseGrade(CourseCode, Grade),
     Program(String), // Assoc'd STEM
-    // Standing(u8), // May be Sen, Ju/Sen, Ju+, or So/Fr only -- how represent? TODO
+    /// Minimum standing; "Ju/Sen" and "Ju+" are both `Standing(Junior)`
+    Standing(ClassStanding),
     Instructor,
     #[default]
     NotRequired,
 }
 
+impl fmt::Display for CourseReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CourseReq::*;
+        match self {
+            And(reqs) | Or(reqs) => {
+                let sep = if matches!(self, And(_)) {
+                    " and "
+                } else {
+                    " or "
+                };
+                let parts: Vec<String> = reqs
+                    .iter()
+                    .map(|r| match r {
+                        And(_) | Or(_) => format!("({})", r),
+                        _ => r.to_string(),
+                    })
+                    .collect();
+                write!(f, "{}", parts.join(sep))
+            }
+            PreCourse(code) => write!(f, "{}", code),
+            CoCourse(code) => write!(f, "{} (concurrent)", code),
+            PreCourseGrade(code, grade) => write!(f, "{} with {} or better", code, grade),
+            CoCourseGrade(code, grade) => {
+                write!(f, "{} (concurrent) with {} or better", code, grade)
+            }
+            Program(stem) => write!(f, "{} program", stem),
+            Standing(standing) => write!(f, "{:?} standing", standing),
+            Instructor => write!(f, "instructor consent"),
+            NotRequired => write!(f, "none"),
+        }
+    }
+}
+
+#[derive(Savefile, Serialize, Deserialize, Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum ClassStanding {
+    Freshman,
+    Sophomore,
+    Junior,
+    Senior,
+}
+
+impl ClassStanding {
+    /// Cumulative credits that must be earned before a semester to hold this standing.
+    pub fn min_credits(&self) -> u32 {
+        match self {
+            ClassStanding::Freshman => 0,
+            ClassStanding::Sophomore => 30,
+            ClassStanding::Junior => 60,
+            ClassStanding::Senior => 90,
+        }
+    }
+}
+
 #[derive(Savefile, Serialize, Deserialize, Debug, Hash, Clone, Copy, PartialEq, Eq)]
 pub enum GradeLetter {
     A,
@@ -60,8 +116,8 @@ impl Ord for GradeQualifier {
             (Plus, Plus) | (Minus, Minus) | (None, None) => std::cmp::Ordering::Equal,
             (Plus, _) => std::cmp::Ordering::Greater,
             (_, Plus) => std::cmp::Ordering::Less,
-            (Minus, _) => std::cmp::Ordering::Greater,
-            (_, Minus) => std::cmp::Ordering::Less,
+            (Minus, _) => std::cmp::Ordering::Less,
+            (_, Minus) => std::cmp::Ordering::Greater,
         }
     }
 }
@@ -86,6 +142,77 @@ impl Ord for Grade {
     }
 }
 
+impl fmt::Display for Grade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.letter)?;
+        match self.qualifier {
+            GradeQualifier::Plus => write!(f, "+"),
+            GradeQualifier::Minus => write!(f, "-"),
+            GradeQualifier::None => Ok(()),
+        }
+    }
+}
+
+/// Grades earned in courses the student has already completed.
+#[derive(Savefile, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    pub grades: HashMap<CourseCode, Grade>,
+}
+
+impl Transcript {
+    /// Whether a completed course counts toward a requirement; without a minimum, any non-F grade counts.
+    pub fn satisfies(&self, code: &CourseCode, min: Option<&Grade>) -> bool {
+        match (self.grades.get(code), min) {
+            (Some(grade), Some(min)) => grade >= min,
+            (Some(grade), None) => grade.letter != GradeLetter::F,
+            (None, _) => false,
+        }
+    }
+
+    pub fn is_passed(&self, code: &CourseCode) -> bool {
+        self.satisfies(code, None)
+    }
+
+    /// Whether a course finished before the plan counts toward a requirement. Graded courses
+    /// must meet `min`; ungraded courses in `history` are in progress and assumed to.
+    pub fn counts_toward(
+        &self,
+        code: &CourseCode,
+        min: Option<&Grade>,
+        history: &[Semester],
+    ) -> bool {
+        if self.grades.contains_key(code) {
+            self.satisfies(code, min)
+        } else {
+            history.iter().flatten().any(|c| c == code)
+        }
+    }
+
+    /// Courses completed with a passing grade.
+    pub fn passed_courses(&self) -> impl Iterator<Item = &CourseCode> {
+        self.grades.keys().filter(|code| self.is_passed(code))
+    }
+}
+
+/// Which courses may be assumed to receive instructor consent when planning.
+#[derive(Savefile, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub enum InstructorConsent {
+    #[default]
+    Never,
+    Always,
+    Courses(Vec<CourseCode>),
+}
+
+impl InstructorConsent {
+    pub fn grants(&self, course: &CourseCode) -> bool {
+        match self {
+            InstructorConsent::Never => false,
+            InstructorConsent::Always => true,
+            InstructorConsent::Courses(codes) => codes.contains(course),
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! GR {
     ($l:ident +) => {
@@ -108,8 +235,6 @@ macro_rules! GR {
     };
 }
 
-// Used for script_assistant crate
-#[allow(dead_code)]
 impl CourseReq {
     pub fn all_course_codes(&self) -> Vec<CourseCode> {
         let mut codes = Vec::new();
@@ -136,23 +261,56 @@ impl CourseReq {
 }
 
 impl CourseReq {
-    pub fn is_satisfied(&self, sched: &Schedule, sem_idx: usize) -> bool {
-        // TODO: grade is not implemented
+    /// Planned (ungraded) courses are assumed to meet any grade threshold; completed
+    /// courses only count when their transcript grade does.
+    pub fn is_satisfied(&self, sched: &Schedule, sem_idx: usize, course: &CourseCode) -> bool {
+        self.is_satisfied_with(sched, sem_idx, course, &sched.instructor_consent)
+    }
+
+    /// Whether `course` is only allowed in this semester because instructor consent is assumed.
+    pub fn needs_consent(&self, sched: &Schedule, sem_idx: usize, course: &CourseCode) -> bool {
+        self.is_satisfied(sched, sem_idx, course)
+            && !self.is_satisfied_with(sched, sem_idx, course, &InstructorConsent::Never)
+    }
+
+    /// Whether the course prerequisites alone are met, where `taken(code, concurrent)` says
+    /// whether `code` is taken before the course or, if `concurrent`, alongside it. Program,
+    /// standing and consent requirements are assumed to be met.
+    pub fn is_met_by(&self, taken: &dyn Fn(&CourseCode, bool) -> bool) -> bool {
+        match self {
+            CourseReq::And(reqs) => reqs.iter().all(|req| req.is_met_by(taken)),
+            CourseReq::Or(reqs) => reqs.iter().any(|req| req.is_met_by(taken)),
+            CourseReq::PreCourse(code) | CourseReq::PreCourseGrade(code, _) => taken(code, false),
+            CourseReq::CoCourse(code) | CourseReq::CoCourseGrade(code, _) => taken(code, true),
+            CourseReq::Program(_)
+            | CourseReq::Standing(_)
+            | CourseReq::Instructor
+            | CourseReq::NotRequired => true,
+        }
+    }
+
+    fn is_satisfied_with(
+        &self,
+        sched: &Schedule,
+        sem_idx: usize,
+        course: &CourseCode,
+        consent: &InstructorConsent,
+    ) -> bool {
         match self {
-            CourseReq::And(reqs) => reqs.iter().all(|req| req.is_satisfied(sched, sem_idx)),
-            CourseReq::Or(reqs) => reqs.iter().any(|req| req.is_satisfied(sched, sem_idx)),
-            CourseReq::PreCourse(code) | CourseReq::PreCourseGrade(code, _) => sched
-                .courses
+            CourseReq::And(reqs) => reqs
                 .iter()
-                .take(sem_idx)
-                .flatten()
-                .any(|c| c == code),
-            CourseReq::CoCourse(code) | CourseReq::CoCourseGrade(code, _) => sched
-                .courses
+                .all(|req| req.is_satisfied_with(sched, sem_idx, course, consent)),
+            CourseReq::Or(reqs) => reqs
                 .iter()
-                .take(sem_idx + 1)
-                .flatten()
-                .any(|c| c == code),
+                .any(|req| req.is_satisfied_with(sched, sem_idx, course, consent)),
+            CourseReq::PreCourse(code) => sched.taken_before(code, sem_idx, None),
+            CourseReq::PreCourseGrade(code, grade) => {
+                sched.taken_before(code, sem_idx, Some(grade))
+            }
+            CourseReq::CoCourse(code) => sched.taken_before(code, sem_idx + 1, None),
+            CourseReq::CoCourseGrade(code, grade) => {
+                sched.taken_before(code, sem_idx + 1, Some(grade))
+            }
             CourseReq::Program(x) => sched.programs.iter().any(|p| {
                 sched
                     .catalog
@@ -160,8 +318,109 @@ impl CourseReq {
                     .iter()
                     .any(|y| y.name == *p && y.assoc_stems.contains(x))
             }),
-            CourseReq::Instructor => todo!(),
+            CourseReq::Standing(standing) => {
+                sched.credits_before(sem_idx) >= standing.min_credits()
+            }
+            CourseReq::Instructor => consent.grants(course),
             CourseReq::NotRequired => true,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::tests::{catalog, code, schedule};
+
+    /// A student who finished CHEM-1200 with `grade` last semester and plans CHEM-2100 next.
+    fn after_general_chemistry(grade: Grade) -> Schedule {
+        let mut sched = schedule(
+            catalog(serde_json::json!({})),
+            &[],
+            vec![vec![code("CHEM-1200")], vec![code("CHEM-2100")]],
+        );
+        sched.locked_semesters = 1;
+        sched.transcript.grades.insert(code("CHEM-1200"), grade);
+        sched
+    }
+
+    #[test]
+    fn grades_order_by_letter_then_qualifier() {
+        assert!(GR!(A) > GR!(B+));
+        assert!(GR!(B+) > GR!(B));
+        assert!(GR!(B) > GR!(B-));
+        assert!(GR!(B-) > GR!(C+));
+        assert!(GR!(D-) > GR!(F));
+    }
+
+    #[test]
+    fn transcripts_count_courses_that_meet_the_minimum() {
+        let transcript = Transcript {
+            grades: HashMap::from([(code("MATH-1300"), GR!(C)), (code("MATH-1350"), GR!(F))]),
+        };
+        assert!(transcript.satisfies(&code("MATH-1300"), Some(&GR!(C-))));
+        assert!(!transcript.satisfies(&code("MATH-1300"), Some(&GR!(C+))));
+        assert!(transcript.is_passed(&code("MATH-1300")));
+        assert!(!transcript.is_passed(&code("MATH-1350")));
+        // Ungraded courses in the history are in progress and assumed to count
+        let history = vec![vec![code("MATH-2300")]];
+        assert!(transcript.counts_toward(&code("MATH-2300"), Some(&GR!(A)), &history));
+        assert!(!transcript.counts_toward(&code("MATH-1350"), None, &history));
+        assert!(!transcript.counts_toward(&code("MATH-3100"), None, &history));
+    }
+
+    #[test]
+    fn graded_prerequisites_check_the_transcript() {
+        let req = CourseReq::PreCourseGrade(code("CHEM-1200"), GR!(C));
+        let chem_2100 = code("CHEM-2100");
+        assert!(req.is_satisfied(&after_general_chemistry(GR!(C)), 1, &chem_2100));
+        assert!(!req.is_satisfied(&after_general_chemistry(GR!(C-)), 1, &chem_2100));
+        // Any passing grade meets an ungraded prerequisite
+        let ungraded = CourseReq::PreCourse(code("CHEM-1200"));
+        assert!(ungraded.is_satisfied(&after_general_chemistry(GR!(D)), 1, &chem_2100));
+        assert!(!ungraded.is_satisfied(&after_general_chemistry(GR!(F)), 1, &chem_2100));
+    }
+
+    #[test]
+    fn failed_courses_are_retaken() {
+        let mut sched = after_general_chemistry(GR!(F));
+        sched.courses[1].push(code("CHEM-1200"));
+        assert!(!sched.completed_courses().contains(&code("CHEM-1200")));
+        sched.reduce().unwrap();
+        assert_eq!(sched.courses[1], vec![code("CHEM-2100"), code("CHEM-1200")]);
+
+        // A passed course isn't planned again
+        let mut sched = after_general_chemistry(GR!(B));
+        sched.courses[1].push(code("CHEM-1200"));
+        sched.reduce().unwrap();
+        assert_eq!(sched.courses[1], vec![code("CHEM-2100")]);
+    }
+
+    #[test]
+    fn courses_relying_on_consent_are_flagged() {
+        let req = CourseReq::Or(vec![
+            CourseReq::PreCourse(code("CHEM-1200")),
+            CourseReq::Instructor,
+        ]);
+        let catalog = catalog(serde_json::json!({
+            "prereqs": {"CHEM-4950": {"Or": [{"PreCourse": "CHEM-1200"}, "Instructor"]}}
+        }));
+        assert_eq!(catalog.prereqs[&code("CHEM-4950")], req);
+        // CHEM-4950 comes before CHEM-1200, so only consent allows it
+        let mut sched = schedule(
+            catalog,
+            &[],
+            vec![vec![code("CHEM-4950")], vec![code("CHEM-1200")]],
+        );
+        let chem_4950 = code("CHEM-4950");
+        assert!(!req.is_satisfied(&sched, 0, &chem_4950));
+        assert!(sched.consent_dependent_courses().is_empty());
+
+        sched.instructor_consent = InstructorConsent::Courses(vec![chem_4950.clone()]);
+        assert!(req.is_satisfied(&sched, 0, &chem_4950));
+        assert!(req.needs_consent(&sched, 0, &chem_4950));
+        assert_eq!(sched.consent_dependent_courses(), [&chem_4950].into());
+        // In a later semester, after CHEM-1200, consent is no longer needed
+        assert!(!req.needs_consent(&sched, 2, &chem_4950));
+    }
+}
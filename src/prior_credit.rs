@@ -0,0 +1,72 @@
+use savefile_derive::Savefile;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::schedule::{Catalog, CourseCode};
+
+/// Where credit earned outside the institution came from.
+#[derive(Savefile, Serialize, Deserialize, Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum PriorCreditSource {
+    AdvancedPlacement,
+    DualEnrollment,
+    Transfer,
+}
+
+impl fmt::Display for PriorCreditSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriorCreditSource::AdvancedPlacement => write!(f, "AP"),
+            PriorCreditSource::DualEnrollment => write!(f, "Dual Enrollment"),
+            PriorCreditSource::Transfer => write!(f, "Transfer"),
+        }
+    }
+}
+
+/// An external course accepted as one or more catalog courses.
+#[derive(Savefile, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Equivalency {
+    pub source: PriorCreditSource,
+    pub name: String,
+    pub equivalents: Vec<CourseCode>,
+}
+
+/// Credit earned before enrolling. It satisfies requirements through its catalog equivalents
+/// but never occupies a semester.
+#[derive(Savefile, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PriorCredit {
+    pub source: PriorCreditSource,
+    pub name: String,
+    pub credits: u32,
+    /// Catalog courses this credit stands in for; empty for elective-only credit
+    pub equivalents: Vec<CourseCode>,
+}
+
+impl PriorCredit {
+    /// Build a prior credit record, mapping it into catalog courses where the catalog lists an
+    /// equivalency.
+    pub fn new(source: PriorCreditSource, name: &str, credits: u32, catalog: &Catalog) -> Self {
+        let equivalents = catalog
+            .equivalencies
+            .iter()
+            .find(|eq| eq.source == source && eq.name == name)
+            .map(|eq| eq.equivalents.clone())
+            .unwrap_or_default();
+        PriorCredit {
+            source,
+            name: name.to_string(),
+            credits,
+            equivalents,
+        }
+    }
+}
+
+impl fmt::Display for PriorCredit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.source, self.name)?;
+        if !self.equivalents.is_empty() {
+            let codes: Vec<String> = self.equivalents.iter().map(|c| c.to_string()).collect();
+            write!(f, " ({})", codes.join(", "))?;
+        }
+        Ok(())
+    }
+}
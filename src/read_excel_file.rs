@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 use crate::schedule::Schedule;
 
-use crate::{SAVEFILE_VERSION, TEMPLATE_PNG};
+use crate::{SAVEFILE_VERSION, TEMPLATE_PNG, VERSION};
 use anyhow::{Result, bail};
 use savefile::prelude::*;
 use umya_spreadsheet::reader::xlsx;
@@ -29,6 +29,15 @@ pub fn read_file(fname: &PathBuf) -> Result<Schedule> {
     }
     let embedded_bytes = &img_bytes[template_len..];
 
-    let sched: Schedule = load_from_mem(embedded_bytes, SAVEFILE_VERSION)?;
+    // The savefile version follows the crate version. Plans saved before 0.2.0 predate program
+    // assignments, credit ranges and the newer prerequisite kinds, so their layout can't be read
+    let sched: Schedule = load_from_mem(embedded_bytes, SAVEFILE_VERSION).map_err(|e| {
+        anyhow::anyhow!(
+            "{} was saved by an incompatible version of ROSS (this is {}): {}",
+            fname.display(),
+            VERSION,
+            e
+        )
+    })?;
     Ok(sched)
 }
@@ -6,8 +6,15 @@ use std::{
     fmt::{self, Display},
 };
 
-use crate::geneds::{GenEd, are_geneds_satisfied};
-use crate::prereqs::CourseReq;
+use crate::catalog_diff::{CatalogDiff, diff_catalogs};
+use crate::geneds::{GenEd, GenEdOverlapPolicy, validate_geneds};
+use crate::prereqs::{CourseReq, Grade, InstructorConsent, Transcript};
+use crate::prior_credit::{Equivalency, PriorCredit};
+use crate::terms::{Season, Term};
+use crate::validation::{
+    CreditLoadViolation, OfferingViolation, PrereqViolation, SharedCreditViolation,
+    ValidationReport,
+};
 
 #[derive(Savefile, Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
 pub enum CourseTermOffering {
@@ -17,6 +24,29 @@ pub enum CourseTermOffering {
     Discretion,
     Infrequently,
     Summer,
+    Winter,
+    /// Fall of odd calendar years
+    FallOdd,
+    /// Fall of even calendar years
+    FallEven,
+    /// Spring of odd calendar years
+    SpringOdd,
+    /// Spring of even calendar years
+    SpringEven,
+    /// Fall and Spring of academic years named by an odd year (e.g. 2025-2026)
+    OddYears,
+    /// Fall and Spring of academic years named by an even year (e.g. 2026-2027)
+    EvenYears,
+}
+
+impl CourseTermOffering {
+    /// Offerings the department doesn't commit to in advance.
+    pub fn is_uncertain(&self) -> bool {
+        matches!(
+            self,
+            CourseTermOffering::Discretion | CourseTermOffering::Infrequently
+        )
+    }
 }
 
 #[derive(Savefile, Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
@@ -67,7 +97,9 @@ impl Display for CourseCodeSuffix {
     }
 }
 
-#[derive(Savefile, Serialize, Deserialize, Clone, Hash, PartialEq, Eq)]
+/// Serialized as its display form, e.g. "PHYS-2100" or "CHEM-COMP", so it can key maps in
+/// catalog files; unique suffixes are written with a leading "#".
+#[derive(Savefile, Clone, Hash, PartialEq, Eq)]
 pub struct CourseCode {
     pub stem: String,
     pub code: CourseCodeSuffix,
@@ -90,6 +122,51 @@ impl Display for CourseCode {
     }
 }
 
+impl std::str::FromStr for CourseCode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (stem, suffix) = s
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("Invalid course code: {}", s))?;
+        let code = if let Some(id) = suffix.strip_prefix('#') {
+            CourseCodeSuffix::Unique(id.parse()?)
+        } else if let Ok(num) = suffix.parse() {
+            CourseCodeSuffix::Number(num)
+        } else {
+            CourseCodeSuffix::Special(suffix.to_string())
+        };
+        Ok(CourseCode {
+            stem: stem.to_ascii_uppercase(),
+            code,
+        })
+    }
+}
+
+impl Serialize for CourseCode {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match &self.code {
+            CourseCodeSuffix::Unique(id) => {
+                serializer.collect_str(&format_args!("{}-#{}", self.stem, id))
+            }
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CourseCode {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl std::fmt::Debug for CourseCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "CC({}-{})", self.stem, self.code.to_string())
@@ -98,7 +175,77 @@ impl std::fmt::Debug for CourseCode {
 
 pub type Semester = Vec<CourseCode>;
 
-#[derive(Savefile, Serialize, Deserialize, Debug, Clone)]
+/// The credits a course carries. Most courses carry a fixed amount, but research, internships
+/// and independent study let the student choose any amount in a range. Serialized as a number
+/// when fixed and as `[min, max]` otherwise.
+#[derive(Savefile, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct CreditRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl CreditRange {
+    pub fn new(min: u32, max: u32) -> Self {
+        CreditRange { min, max }
+    }
+
+    pub fn fixed(credits: u32) -> Self {
+        CreditRange {
+            min: credits,
+            max: credits,
+        }
+    }
+
+    pub fn is_fixed(&self) -> bool {
+        self.min == self.max
+    }
+}
+
+impl Display for CreditRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_fixed() {
+            write!(f, "{}", self.min)
+        } else {
+            write!(f, "{}-{}", self.min, self.max)
+        }
+    }
+}
+
+impl Serialize for CreditRange {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        if self.is_fixed() {
+            self.min.serialize(serializer)
+        } else {
+            (self.min, self.max).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CreditRange {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Fixed(u32),
+            Range(u32, u32),
+        }
+        match Repr::deserialize(deserializer)? {
+            Repr::Fixed(credits) => Ok(CreditRange::fixed(credits)),
+            Repr::Range(min, max) if min <= max => Ok(CreditRange::new(min, max)),
+            Repr::Range(min, max) => Err(serde::de::Error::custom(format!(
+                "Invalid credit range: {}-{}",
+                min, max
+            ))),
+        }
+    }
+}
+
+#[derive(Savefile, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum Elective {
     And(Vec<Elective>),
     Or(Vec<Elective>),
@@ -107,23 +254,135 @@ pub enum Elective {
     Sequence(Vec<Vec<CourseCode>>),
 }
 
+impl Elective {
+    pub fn all_course_codes(&self) -> Vec<CourseCode> {
+        let mut codes = Vec::new();
+        self.collect_course_codes(&mut codes);
+        codes.into_iter().cloned().collect()
+    }
+
+    /// Lists of courses that stand in for each other: the options of each `Courses`, `Credits` or
+    /// `Sequence` requirement, with everything under an `Or` pooled together.
+    pub fn option_lists(&self) -> Vec<Vec<&CourseCode>> {
+        match self {
+            Elective::And(reqs) => reqs.iter().flat_map(|req| req.option_lists()).collect(),
+            Elective::Or(_) => {
+                let mut codes = Vec::new();
+                self.collect_course_codes(&mut codes);
+                vec![codes]
+            }
+            Elective::Courses { opts, .. } | Elective::Credits { opts, .. } => {
+                vec![opts.iter().collect()]
+            }
+            Elective::Sequence(seqs) => vec![seqs.iter().flatten().collect()],
+        }
+    }
+
+    fn collect_course_codes<'a>(&'a self, codes: &mut Vec<&'a CourseCode>) {
+        match self {
+            Elective::And(reqs) | Elective::Or(reqs) => {
+                for req in reqs {
+                    req.collect_course_codes(codes);
+                }
+            }
+            Elective::Courses { opts, .. } | Elective::Credits { opts, .. } => {
+                codes.extend(opts.iter());
+            }
+            Elective::Sequence(seqs) => {
+                codes.extend(seqs.iter().flatten());
+            }
+        }
+    }
+
+    /// Sequence is satisfied when any one of its course lists is taken in full. `credits` gives
+    /// the credits each course carries.
+    pub fn is_satisfied(
+        &self,
+        sched_courses: &HashSet<&CourseCode>,
+        credits: &dyn Fn(&CourseCode) -> u32,
+    ) -> bool {
+        match self {
+            Elective::And(reqs) => reqs
+                .iter()
+                .all(|req| req.is_satisfied(sched_courses, credits)),
+            Elective::Or(reqs) => reqs
+                .iter()
+                .any(|req| req.is_satisfied(sched_courses, credits)),
+            Elective::Courses { num, opts } => {
+                opts.iter().filter(|c| sched_courses.contains(c)).count() >= *num
+            }
+            Elective::Credits { num, opts } => {
+                opts.iter()
+                    .filter(|c| sched_courses.contains(c))
+                    .map(|c| credits(c) as usize)
+                    .sum::<usize>()
+                    >= *num
+            }
+            Elective::Sequence(seqs) => seqs
+                .iter()
+                .any(|seq| seq.iter().all(|c| sched_courses.contains(c))),
+        }
+    }
+}
+
+/// Majors lay their courses out semester by semester; minors and certificates only list them.
+#[derive(Savefile, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgramKind {
+    Major,
+    Minor,
+    Certificate,
+}
+
 #[derive(Savefile, Serialize, Deserialize, Debug, Clone)]
 pub struct Program {
     pub(crate) name: String,
+    pub(crate) kind: ProgramKind,
+    /// Semester-by-semester template; empty for minors and certificates
+    #[serde(default)]
     pub(crate) semesters: Vec<Semester>,
+    /// Required courses with no place in the template
+    #[serde(default)]
+    pub(crate) required: Vec<CourseCode>,
+    #[serde(default)]
     pub(crate) electives: Vec<Elective>,
+    #[serde(default)]
     pub(crate) assoc_stems: Vec<String>,
+    /// Most credits of this program that may also count toward another of the student's
+    /// programs; `None` for no limit
+    #[serde(default)]
+    pub(crate) max_shared_credits: Option<u32>,
+}
+
+impl Program {
+    /// Every required course, whether or not it has a semester in the template.
+    pub fn required_courses(&self) -> impl Iterator<Item = &CourseCode> {
+        self.semesters.iter().flatten().chain(self.required.iter())
+    }
 }
 
 #[derive(Savefile, Serialize, Deserialize, Debug, Clone)]
 pub struct Catalog {
     pub programs: Vec<Program>,
     pub geneds: Vec<GenEd>,
+    pub gened_overlap: GenEdOverlapPolicy,
     pub prereqs: HashMap<CourseCode, CourseReq>,
-    pub courses: HashMap<CourseCode, (String, Option<u32>, CourseTermOffering)>,
+    /// Name, credits (`None` when unknown) and term offering
+    pub courses: HashMap<CourseCode, (String, Option<CreditRange>, CourseTermOffering)>,
+    #[serde(default)]
+    pub equivalencies: Vec<Equivalency>,
     pub low_year: u32,
 }
 
+impl Catalog {
+    /// The least credits `code` carries; 0 for unlisted courses and those with unknown credits.
+    pub fn min_credits(&self, code: &CourseCode) -> u32 {
+        self.courses
+            .get(code)
+            .and_then(|(_, cr, _)| *cr)
+            .map_or(0, |cr| cr.min)
+    }
+}
+
 impl PartialEq for Catalog {
     fn eq(&self, other: &Self) -> bool {
         self.low_year == other.low_year // Assumes that no two Catalogs will have the same low_year
@@ -142,20 +401,135 @@ impl fmt::Display for Catalog {
     }
 }
 
+/// Minimum and maximum credits allowed in one semester.
+#[derive(Savefile, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreditLimits {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Default for CreditLimits {
+    /// Full-time status (12 credits) up to the standard 18-credit cap.
+    fn default() -> Self {
+        CreditLimits { min: 12, max: 18 }
+    }
+}
+
+/// Credit limits for a run, with overrides for individual semesters (e.g. a lighter final
+/// semester, or an approved 21-credit overload).
+#[derive(Savefile, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CreditPolicy {
+    pub default: CreditLimits,
+    /// Overrides keyed by index into `Schedule::courses`
+    pub semesters: HashMap<usize, CreditLimits>,
+    /// Opt into summer sessions with these limits; `None` plans no summers
+    pub summer: Option<CreditLimits>,
+    /// Opt into winter sessions with these limits; `None` plans no winters
+    pub winter: Option<CreditLimits>,
+}
+
+impl CreditPolicy {
+    pub fn limits(&self, sem_idx: usize, term: &Term) -> CreditLimits {
+        let session = match term.season {
+            Season::Summer => self.summer,
+            Season::Winter => self.winter,
+            Season::Fall | Season::Spring => None,
+        };
+        self.semesters
+            .get(&sem_idx)
+            .copied()
+            .or(session)
+            .unwrap_or(self.default)
+    }
+}
+
 #[derive(Savefile, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Schedule {
     pub courses: Vec<Semester>,
+    /// The term of each entry in `courses`
+    pub terms: Vec<Term>,
+    /// The student's first term, which need not be a Fall
+    pub start: Term,
     pub programs: Vec<String>,
     pub catalog: Catalog,
+    pub transcript: Transcript,
+    /// AP, dual-enrollment and transfer credit that never occupies a semester
+    pub prior_credit: Vec<PriorCredit>,
+    pub instructor_consent: InstructorConsent,
+    pub credit_policy: CreditPolicy,
+    /// Leading semesters that are completed or in progress; the solver never changes them.
+    pub locked_semesters: usize,
+    /// The geneds each course counts toward, as chosen by the solver
+    pub gened_assignments: HashMap<CourseCode, Vec<String>>,
+    /// The programs each course serves, as chosen by the solver
+    pub program_assignments: HashMap<CourseCode, Vec<String>>,
+    /// Credits chosen by the solver for planned variable-credit courses
+    pub chosen_credits: HashMap<CourseCode, u32>,
 }
 
-pub fn generate_schedule(programs: Vec<&str>, catalog: Catalog) -> Result<Schedule> {
+/// Everything about the student a schedule is planned around besides their programs, catalog and
+/// semesters already taken.
+#[derive(Debug, Clone)]
+pub struct ScheduleOptions {
+    pub transcript: Transcript,
+    pub prior_credit: Vec<PriorCredit>,
+    pub instructor_consent: InstructorConsent,
+    pub credit_policy: CreditPolicy,
+    /// The student's first term, which need not be a Fall
+    pub start: Term,
+}
+
+impl ScheduleOptions {
+    /// A new student starting in `start`, with no record and the default credit policy.
+    pub fn starting(start: Term) -> Self {
+        ScheduleOptions {
+            transcript: Transcript::default(),
+            prior_credit: vec![],
+            instructor_consent: InstructorConsent::default(),
+            credit_policy: CreditPolicy::default(),
+            start,
+        }
+    }
+}
+
+pub fn generate_schedule(
+    programs: Vec<&str>,
+    catalog: Catalog,
+    options: ScheduleOptions,
+) -> Result<Schedule> {
+    replan_schedule(programs, catalog, options, vec![])
+}
+
+/// Plan the remaining semesters around a student's completed and in-progress `history`,
+/// which is kept exactly as given and fills the terms from `start` onward.
+pub fn replan_schedule(
+    programs: Vec<&str>,
+    catalog: Catalog,
+    options: ScheduleOptions,
+    history: Vec<Semester>,
+) -> Result<Schedule> {
+    let ScheduleOptions {
+        transcript,
+        prior_credit,
+        instructor_consent,
+        credit_policy,
+        start,
+    } = options;
     // (catalog: )
+    if let Some(unknown) = programs
+        .iter()
+        .find(|name| !catalog.programs.iter().any(|p| p.name == **name))
+    {
+        anyhow::bail!("Program {} not found in catalog", unknown);
+    }
     let programs: Vec<&Program> = catalog
         .programs
         .iter()
         .filter(|p| programs.contains(&p.name.as_str()))
         .collect();
+    if !programs.is_empty() && !programs.iter().any(|p| p.kind == ProgramKind::Major) {
+        anyhow::bail!("Minors and certificates must be scheduled alongside a major");
+    }
 
     let mut combined_semesters: Vec<Semester> = vec![];
     for prog in programs.iter() {
@@ -168,85 +542,769 @@ pub fn generate_schedule(programs: Vec<&str>, catalog: Catalog) -> Result<Schedu
         }
     }
 
+    // Minor and certificate courses have no template semester
+    let mut untemplated: Semester = programs
+        .iter()
+        .flat_map(|p| p.required.iter().cloned())
+        .collect();
+
+    // Courses already completed (or covered by prior credit) don't need to be planned again
+    combined_semesters
+        .iter_mut()
+        .chain(std::iter::once(&mut untemplated))
+        .for_each(|sem| {
+            sem.retain(|code| {
+                !transcript.counts_toward(code, None, &history)
+                    && !prior_credit.iter().any(|p| p.equivalents.contains(code))
+            });
+        });
+
+    // Lay out terms from the student's start until every template semester has a regular term;
+    // opted-in Summer and Winter sessions start out empty
+    let locked_semesters = history.len();
+    let mut terms: Vec<Term> = vec![];
+    let mut term = start;
+    while terms.len() < locked_semesters
+        || terms.iter().filter(|t| t.is_regular()).count() < combined_semesters.len()
+    {
+        terms.push(term);
+        term = term.next(&credit_policy);
+    }
+    let mut courses: Vec<Semester> = vec![vec![]; terms.len()];
+    let regular_idxs = terms
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| t.is_regular())
+        .map(|(idx, _)| idx);
+
+    // Template courses from semesters already in the past are overdue: plan them next, along
+    // with untemplated courses, and let the solver place them all
+    let mut overdue: Semester = untemplated;
+    for (sem, idx) in combined_semesters.into_iter().zip(regular_idxs) {
+        if idx < locked_semesters {
+            overdue.extend(sem);
+        } else {
+            courses[idx] = sem;
+        }
+    }
+    for (idx, sem) in history.into_iter().enumerate() {
+        courses[idx] = sem;
+    }
+    if !overdue.is_empty() {
+        match terms
+            .iter()
+            .skip(locked_semesters)
+            .position(|t| t.is_regular())
+        {
+            Some(pos) => courses[locked_semesters + pos].append(&mut overdue),
+            None => {
+                while !term.is_regular() {
+                    terms.push(term);
+                    courses.push(vec![]);
+                    term = term.next(&credit_policy);
+                }
+                terms.push(term);
+                courses.push(overdue);
+            }
+        }
+    }
+
     let mut sched = Schedule {
-        courses: combined_semesters,
+        courses,
+        terms,
+        start,
         programs: programs.iter().map(|x| x.name.to_owned()).collect(),
         catalog,
+        transcript,
+        prior_credit,
+        instructor_consent,
+        credit_policy,
+        locked_semesters,
+        gened_assignments: HashMap::new(),
+        program_assignments: HashMap::new(),
+        chosen_credits: HashMap::new(),
     };
     sched.reduce()?;
-    println!("Is schedule valid? {}", sched.is_valid()?);
-    crate::model::two_stage_lex_schedule(&mut sched, crate::MAX_CREDITS_PER_SEMESTER)?;
+    println!("Is schedule valid? {}", sched.validate()?.is_valid());
+    crate::model::two_stage_lex_schedule(&mut sched)?;
 
     Ok(sched)
 }
 
 impl Schedule {
+    /// Remove duplicate planned courses and planned courses that are already completed.
+    /// Locked semesters are left alone, so a failed attempt there can be retaken later.
     pub fn reduce<'a>(&'a mut self) -> Result<&'a mut Self> {
-        let mut all_codes: HashSet<CourseCode> = HashSet::new();
-        self.courses.iter_mut().for_each(|sem| {
-            sem.retain(|code| {
-                if !all_codes.contains(code) {
-                    all_codes.insert(code.clone()); // TODO: is cloning necessary?
-                    true
-                } else {
-                    false
-                }
+        let mut all_codes: HashSet<CourseCode> =
+            self.completed_courses().into_iter().cloned().collect();
+        let locked_semesters = self.locked_semesters;
+        self.courses
+            .iter_mut()
+            .skip(locked_semesters)
+            .for_each(|sem| {
+                sem.retain(|code| {
+                    if !all_codes.contains(code) {
+                        all_codes.insert(code.clone()); // TODO: is cloning necessary?
+                        true
+                    } else {
+                        false
+                    }
+                });
             });
-        });
         Ok(self)
     }
 
-    pub fn is_valid(&self) -> Result<bool> {
-        Ok(dbg!(self.are_programs_valid()?)
-            && dbg!(self.validate_prereqs()?)
-            && dbg!(self.are_geneds_fulfilled()?))
+    /// Re-plan the student's remaining semesters under a newer `catalog`, keeping their history,
+    /// and report how the requirements they're held to change.
+    pub fn replan_under(&self, catalog: Catalog) -> Result<(Schedule, CatalogDiff)> {
+        if catalog.low_year < self.catalog.low_year {
+            anyhow::bail!(
+                "Can't move from the {} catalog back to {}",
+                self.catalog,
+                catalog
+            );
+        }
+        let sched = replan_schedule(
+            self.programs.iter().map(String::as_str).collect(),
+            catalog,
+            self.options(),
+            self.history().to_vec(),
+        )?;
+        let courses: HashSet<&CourseCode> = self
+            .taken_courses()
+            .into_iter()
+            .chain(sched.taken_courses())
+            .collect();
+        let diff = diff_catalogs(&self.catalog, &sched.catalog, &self.programs, &courses);
+        Ok((sched, diff))
+    }
+
+    /// The options this schedule was planned with.
+    pub fn options(&self) -> ScheduleOptions {
+        ScheduleOptions {
+            transcript: self.transcript.clone(),
+            prior_credit: self.prior_credit.clone(),
+            instructor_consent: self.instructor_consent.clone(),
+            credit_policy: self.credit_policy.clone(),
+            start: self.start,
+        }
+    }
+
+    /// The credits `code` carries in this schedule: the amount chosen for variable-credit
+    /// courses, and otherwise the catalog's.
+    pub fn credits(&self, code: &CourseCode) -> u32 {
+        self.chosen_credits
+            .get(code)
+            .copied()
+            .unwrap_or_else(|| self.catalog.min_credits(code))
     }
 
-    fn are_geneds_fulfilled(&self) -> Result<bool> {
-        are_geneds_satisfied(self)
+    /// Completed and in-progress semesters.
+    pub fn history(&self) -> &[Semester] {
+        &self.courses[..self.locked_semesters.min(self.courses.len())]
     }
 
-    fn are_programs_valid(&self) -> Result<bool> {
-        let all_sched_codes = self
-            .courses
+    /// Courses completed before the plan starts: passed transcript courses, history courses
+    /// that weren't failed, and prior credit equivalents.
+    pub fn completed_courses(&self) -> HashSet<&CourseCode> {
+        self.history()
             .iter()
             .flatten()
-            .collect::<HashSet<&CourseCode>>();
-        Ok(self
-            .programs
+            .filter(|code| self.transcript.counts_toward(code, None, self.history()))
+            .chain(self.transcript.passed_courses())
+            .chain(self.prior_credit.iter().flat_map(|p| p.equivalents.iter()))
+            .collect()
+    }
+
+    /// Whether `code` was completed before the plan starts in a way that meets `min`.
+    pub fn completes(&self, code: &CourseCode, min: Option<&Grade>) -> bool {
+        self.transcript.counts_toward(code, min, self.history())
+            || self
+                .prior_credit
+                .iter()
+                .any(|p| p.equivalents.contains(code))
+    }
+
+    /// Credits earned before the plan starts. Prior credit counts its own credits rather than
+    /// those of its catalog equivalents.
+    pub fn completed_credits(&self) -> u32 {
+        let prior_codes: HashSet<&CourseCode> = self
+            .prior_credit
             .iter()
-            .map(|prog_name| {
-                let prog = self
-                    .catalog
-                    .programs
-                    .iter()
-                    .find(|p| p.name == *prog_name)
-                    .ok_or_else(|| anyhow::anyhow!("Program {} not found in catalog", prog_name))?;
-                let all_prog_codes = prog
-                    .semesters
+            .flat_map(|p| p.equivalents.iter())
+            .collect();
+        self.completed_courses()
+            .difference(&prior_codes)
+            .map(|code| self.credits(code))
+            .sum::<u32>()
+            + self.prior_credit.iter().map(|p| p.credits).sum::<u32>()
+    }
+
+    /// Whether `code` is completed, or planned in a semester before `end`, in a way that meets `min`.
+    pub fn taken_before(&self, code: &CourseCode, end: usize, min: Option<&Grade>) -> bool {
+        self.completes(code, min)
+            || self
+                .courses
+                .iter()
+                .take(end)
+                .skip(self.locked_semesters)
+                .flatten()
+                .any(|c| c == code)
+    }
+
+    /// Check the schedule against its programs, prerequisites and geneds.
+    pub fn validate(&self) -> Result<ValidationReport> {
+        let mut report = ValidationReport::default();
+        self.validate_programs(&mut report)?;
+        self.validate_prereqs(&mut report);
+        self.validate_credit_loads(&mut report);
+        self.validate_offerings(&mut report);
+        validate_geneds(self, &mut report);
+        Ok(report)
+    }
+
+    /// Cumulative credits earned before planned semester `sem_idx`, including completed courses.
+    pub fn credits_before(&self, sem_idx: usize) -> u32 {
+        let completed = self.completed_courses();
+        self.courses
+            .iter()
+            .take(sem_idx)
+            .skip(self.locked_semesters)
+            .flatten()
+            .filter(|code| !completed.contains(code))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|code| self.credits(code))
+            .sum::<u32>()
+            + self.completed_credits()
+    }
+
+    /// All planned courses plus completed courses.
+    pub fn taken_courses(&self) -> HashSet<&CourseCode> {
+        self.courses
+            .iter()
+            .skip(self.locked_semesters)
+            .flatten()
+            .chain(self.completed_courses())
+            .collect()
+    }
+
+    fn validate_programs(&self, report: &mut ValidationReport) -> Result<()> {
+        let all_sched_codes = self.taken_courses();
+        for prog_name in &self.programs {
+            let prog = self
+                .catalog
+                .programs
+                .iter()
+                .find(|p| p.name == *prog_name)
+                .ok_or_else(|| anyhow::anyhow!("Program {} not found in catalog", prog_name))?;
+            let mut missing: Vec<&CourseCode> = prog
+                .required_courses()
+                .filter(|code| !all_sched_codes.contains(code))
+                .collect();
+            missing.dedup();
+            report.missing_program_courses.extend(
+                missing
+                    .into_iter()
+                    .map(|code| (prog_name.clone(), code.clone())),
+            );
+            // Electives only count courses that serve this program
+            let serving: HashSet<&CourseCode> = all_sched_codes
+                .iter()
+                .copied()
+                .filter(|code| self.serves(code, prog))
+                .collect();
+            if !prog
+                .electives
+                .iter()
+                .all(|elec| elec.is_satisfied(&serving, &|code| self.credits(code)))
+            {
+                report.unmet_electives.push(prog_name.clone());
+            }
+        }
+        self.validate_shared_credits(report);
+        Ok(())
+    }
+
+    /// Whether `code` counts toward `program`: as the solver assigned it, or, before the solver
+    /// has run, whenever the program lists it.
+    fn serves(&self, code: &CourseCode, program: &Program) -> bool {
+        if self.program_assignments.is_empty() {
+            program.required_courses().any(|c| c == code)
+                || program
+                    .electives
                     .iter()
-                    .flatten()
-                    .collect::<HashSet<&CourseCode>>();
-                Ok(all_sched_codes.is_superset(&all_prog_codes))
-            })
-            .collect::<Result<Vec<_>>>()?
+                    .any(|elec| elec.all_course_codes().contains(code))
+        } else {
+            self.program_assignments
+                .get(code)
+                .is_some_and(|programs| programs.contains(&program.name))
+        }
+    }
+
+    /// Courses serving two programs at once must fit within the smaller of the two programs'
+    /// shared-credit limits.
+    fn validate_shared_credits(&self, report: &mut ValidationReport) {
+        let programs: Vec<&Program> = self
+            .catalog
+            .programs
             .iter()
-            .all(|x| *x))
+            .filter(|p| self.programs.contains(&p.name))
+            .collect();
+        for (i, program) in programs.iter().enumerate() {
+            for other in &programs[i + 1..] {
+                let Some(max) = [program.max_shared_credits, other.max_shared_credits]
+                    .into_iter()
+                    .flatten()
+                    .min()
+                else {
+                    continue;
+                };
+                let credits = self
+                    .taken_courses()
+                    .into_iter()
+                    .filter(|code| self.serves(code, program) && self.serves(code, other))
+                    .map(|code| self.credits(code))
+                    .sum();
+                if credits > max {
+                    report.shared_credit_violations.push(SharedCreditViolation {
+                        programs: (program.name.clone(), other.name.clone()),
+                        credits,
+                        max,
+                    });
+                }
+            }
+        }
     }
 
-    pub fn validate_prereqs(&self) -> Result<bool> {
-        for (sem_idx, sem) in self.courses.iter().enumerate() {
+    pub fn validate_prereqs(&self, report: &mut ValidationReport) {
+        for (sem_idx, sem) in self.courses.iter().enumerate().skip(self.locked_semesters) {
             for code in sem {
                 let req = self
                     .catalog
                     .prereqs
                     .get(code)
                     .unwrap_or(&CourseReq::NotRequired);
-                if !req.is_satisfied(self, sem_idx) {
-                    return Ok(false);
+                if !req.is_satisfied(self, sem_idx, code) {
+                    report.prereq_violations.push(PrereqViolation {
+                        course: code.clone(),
+                        semester: sem_idx,
+                        req: req.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    pub fn validate_credit_loads(&self, report: &mut ValidationReport) {
+        for (sem_idx, sem) in self.courses.iter().enumerate().skip(self.locked_semesters) {
+            let credits = sem.iter().map(|code| self.credits(code)).sum();
+            let limits = self.credit_policy.limits(sem_idx, &self.terms[sem_idx]);
+            if credits < limits.min || credits > limits.max {
+                report.credit_violations.push(CreditLoadViolation {
+                    semester: sem_idx,
+                    credits,
+                    limits,
+                });
+            }
+        }
+    }
+
+    pub fn validate_offerings(&self, report: &mut ValidationReport) {
+        for (sem_idx, sem) in self.courses.iter().enumerate().skip(self.locked_semesters) {
+            let term = self.terms[sem_idx];
+            for code in sem {
+                let offering = self.catalog.courses.get(code).map(|(_, _, off)| off);
+                if !term.offers(offering) {
+                    report.offering_violations.push(OfferingViolation {
+                        course: code.clone(),
+                        term,
+                        offering: offering.cloned(),
+                    });
                 }
             }
         }
-        Ok(true)
+    }
+
+    /// Lists of courses that stand in for each other: the options of the student's programs'
+    /// electives and of each gened.
+    pub fn option_lists(&self) -> Vec<Vec<&CourseCode>> {
+        let mut option_lists: Vec<Vec<&CourseCode>> = self
+            .catalog
+            .programs
+            .iter()
+            .filter(|p| self.programs.contains(&p.name))
+            .flat_map(|p| p.electives.iter().flat_map(|e| e.option_lists()))
+            .collect();
+        option_lists.extend(self.catalog.geneds.iter().map(|g| g.req().course_codes()));
+        option_lists
+    }
+
+    /// Reliably offered courses that could stand in for `code` if it's on an uncertain offering;
+    /// empty when `code` is reliably offered or has no such alternative.
+    pub fn reliable_alternatives(&self, code: &CourseCode) -> HashSet<&CourseCode> {
+        let uncertain = |code: &CourseCode| {
+            self.catalog
+                .courses
+                .get(code)
+                .map(|(_, _, off)| off.is_uncertain())
+        };
+        if uncertain(code) != Some(true) {
+            return HashSet::new();
+        }
+        self.option_lists()
+            .into_iter()
+            .filter(|opts| opts.contains(&code))
+            .flatten()
+            .filter(|alt| uncertain(alt) == Some(false))
+            .collect()
+    }
+
+    /// Planned courses on an uncertain offering that a reliably offered course the student isn't
+    /// taking could have stood in for, so the plan may need to change.
+    pub fn at_risk_courses(&self) -> HashSet<&CourseCode> {
+        let taken = self.taken_courses();
+        self.courses
+            .iter()
+            .skip(self.locked_semesters)
+            .flatten()
+            .filter(|code| {
+                self.reliable_alternatives(code)
+                    .iter()
+                    .any(|alt| !taken.contains(alt))
+            })
+            .collect()
+    }
+
+    /// Scheduled courses whose prerequisites are only met by assuming instructor consent.
+    pub fn consent_dependent_courses(&self) -> HashSet<&CourseCode> {
+        self.courses
+            .iter()
+            .enumerate()
+            .skip(self.locked_semesters)
+            .flat_map(|(sem_idx, sem)| sem.iter().map(move |code| (sem_idx, code)))
+            .filter(|(sem_idx, code)| {
+                self.catalog
+                    .prereqs
+                    .get(*code)
+                    .is_some_and(|req| req.needs_consent(self, *sem_idx, code))
+            })
+            .map(|(_, code)| code)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// An empty 2025 catalog with the given top-level `fields` filled in.
+    pub(crate) fn catalog(fields: serde_json::Value) -> Catalog {
+        let mut json = serde_json::json!({
+            "programs": [],
+            "geneds": [],
+            "gened_overlap": {
+                "max_core": null,
+                "max_foundations": null,
+                "max_skills_and_perspectives": null,
+                "allowed_foundation_pairs": []
+            },
+            "prereqs": {},
+            "courses": {},
+            "low_year": 2025
+        });
+        for (key, value) in fields.as_object().unwrap() {
+            json[key] = value.clone();
+        }
+        serde_json::from_value(json).unwrap()
+    }
+
+    pub(crate) fn schedule(
+        catalog: Catalog,
+        programs: &[&str],
+        courses: Vec<Semester>,
+    ) -> Schedule {
+        let start = Term::new(Season::Fall, 2025);
+        let policy = CreditPolicy::default();
+        let mut terms = vec![start];
+        while terms.len() < courses.len() {
+            terms.push(terms[terms.len() - 1].next(&policy));
+        }
+        Schedule {
+            courses,
+            terms,
+            start,
+            programs: programs.iter().map(|p| p.to_string()).collect(),
+            catalog,
+            transcript: Transcript::default(),
+            prior_credit: vec![],
+            instructor_consent: InstructorConsent::default(),
+            credit_policy: policy,
+            locked_semesters: 0,
+            gened_assignments: HashMap::new(),
+            program_assignments: HashMap::new(),
+            chosen_credits: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn code(s: &str) -> CourseCode {
+        s.parse().unwrap()
+    }
+
+    /// A major and a minor that both list CHEM-1200 but share at most 3 credits.
+    fn chemistry_with_minor() -> Schedule {
+        let catalog = catalog(serde_json::json!({
+            "programs": [
+                {"name": "BS Chemistry", "kind": "Major", "semesters": [["CHEM-1200", "CHEM-1300"]]},
+                {"name": "Chemistry Minor", "kind": "Minor", "required": ["CHEM-1200"],
+                 "electives": [{"Courses": {"num": 1, "opts": ["CHEM-1200", "CHEM-2100"]}}],
+                 "max_shared_credits": 3}
+            ],
+            "courses": {
+                "CHEM-1200": ["General Chemistry I", 4, "Fall"],
+                "CHEM-1300": ["General Chemistry II", 4, "Fall"],
+                "CHEM-2100": ["Organic Chemistry I", 4, "Fall"]
+            }
+        }));
+        schedule(
+            catalog,
+            &["BS Chemistry", "Chemistry Minor"],
+            vec![vec![
+                code("CHEM-1200"),
+                code("CHEM-1300"),
+                code("CHEM-2100"),
+            ]],
+        )
+    }
+
+    #[test]
+    fn listed_courses_count_as_shared_before_solving() {
+        let mut report = ValidationReport::default();
+        chemistry_with_minor().validate_shared_credits(&mut report);
+        assert_eq!(report.shared_credit_violations.len(), 1);
+        assert_eq!(report.shared_credit_violations[0].credits, 4);
+        assert_eq!(report.shared_credit_violations[0].max, 3);
+    }
+
+    #[test]
+    fn shared_credits_follow_program_assignments() {
+        let mut sched = chemistry_with_minor();
+        let major = vec!["BS Chemistry".to_string()];
+        let minor = vec!["Chemistry Minor".to_string()];
+        sched.program_assignments = HashMap::from([
+            (code("CHEM-1200"), major.clone()),
+            (code("CHEM-1300"), major),
+            (code("CHEM-2100"), minor),
+        ]);
+        let mut report = ValidationReport::default();
+        sched.validate_shared_credits(&mut report);
+        assert!(report.shared_credit_violations.is_empty());
+    }
+
+    #[test]
+    fn electives_only_count_courses_assigned_to_the_program() {
+        let mut sched = chemistry_with_minor();
+        let both = vec!["BS Chemistry".to_string(), "Chemistry Minor".to_string()];
+        sched.program_assignments = HashMap::from([
+            (code("CHEM-1200"), both),
+            (code("CHEM-2100"), vec!["BS Chemistry".to_string()]),
+        ]);
+        let mut report = ValidationReport::default();
+        sched.validate_programs(&mut report).unwrap();
+        assert!(report.unmet_electives.is_empty());
+
+        sched.program_assignments.remove(&code("CHEM-1200"));
+        let mut report = ValidationReport::default();
+        sched.validate_programs(&mut report).unwrap();
+        assert_eq!(report.unmet_electives, vec!["Chemistry Minor".to_string()]);
+    }
+
+    #[test]
+    fn credit_ranges_round_trip_through_json() {
+        let fixed: CreditRange = serde_json::from_str("3").unwrap();
+        let range: CreditRange = serde_json::from_str("[1, 4]").unwrap();
+        assert_eq!(fixed, CreditRange::fixed(3));
+        assert_eq!(range, CreditRange::new(1, 4));
+        assert_eq!(serde_json::to_string(&fixed).unwrap(), "3");
+        assert_eq!(serde_json::to_string(&range).unwrap(), "[1,4]");
+        assert!(
+            serde_json::from_str::<CreditRange>("[2, 2]")
+                .unwrap()
+                .is_fixed()
+        );
+        assert!(serde_json::from_str::<CreditRange>("[4, 1]").is_err());
+    }
+
+    #[test]
+    fn elective_credits_count_the_chosen_amount() {
+        let catalog = catalog(serde_json::json!({
+            "programs": [{"name": "BS Physics", "kind": "Major", "semesters": [["PHYS-2100"]],
+                          "electives": [{"Credits": {"num": 4, "opts": ["PHYS-4900"]}}]}],
+            "courses": {
+                "PHYS-2100": ["University Physics I", 4, "Both"],
+                "PHYS-4900": ["Research", [1, 4], "Both"]
+            }
+        }));
+        let mut sched = schedule(
+            catalog,
+            &["BS Physics"],
+            vec![vec![code("PHYS-2100"), code("PHYS-4900")]],
+        );
+        // Before the solver chooses, a variable-credit course carries its minimum
+        let mut report = ValidationReport::default();
+        sched.validate_programs(&mut report).unwrap();
+        assert_eq!(report.unmet_electives, vec!["BS Physics".to_string()]);
+
+        sched.chosen_credits.insert(code("PHYS-4900"), 4);
+        let mut report = ValidationReport::default();
+        sched.validate_programs(&mut report).unwrap();
+        assert!(report.unmet_electives.is_empty());
+        assert_eq!(sched.credits(&code("PHYS-4900")), 4);
+    }
+
+    #[test]
+    fn only_uncertain_courses_with_reliable_alternatives_are_at_risk() {
+        let catalog = catalog(serde_json::json!({
+            "programs": [
+                {"name": "BS Computer Science", "kind": "Major", "semesters": [["CS-4900"]],
+                 "electives": [{"Courses": {"num": 1, "opts": ["CS-3900", "CS-3100", "CS-3950"]}}]}
+            ],
+            "courses": {
+                "CS-3100": ["Algorithms", 3, "Fall"],
+                "CS-3900": ["Special Topics", 3, "Discretion"],
+                "CS-3950": ["Seminar", 3, "Infrequently"],
+                "CS-4900": ["Capstone", 3, "Infrequently"]
+            }
+        }));
+        let mut sched = schedule(
+            catalog,
+            &["BS Computer Science"],
+            vec![vec![code("CS-3900"), code("CS-4900")]],
+        );
+        assert_eq!(
+            sched.reliable_alternatives(&code("CS-3900")),
+            HashSet::from([&code("CS-3100")])
+        );
+        assert!(sched.reliable_alternatives(&code("CS-3100")).is_empty());
+        // A required course has nothing to stand in for it
+        assert!(sched.reliable_alternatives(&code("CS-4900")).is_empty());
+        assert_eq!(sched.at_risk_courses(), HashSet::from([&code("CS-3900")]));
+
+        // Once the reliable option is taken too, it no longer stands in
+        sched.courses[0].push(code("CS-3100"));
+        assert!(sched.at_risk_courses().is_empty());
+    }
+
+    #[test]
+    fn electives_are_satisfied_by_each_kind_of_requirement() {
+        let codes = |list: &[&str]| list.iter().map(|c| code(c)).collect::<Vec<_>>();
+        let taken_codes = codes(&["PHYS-2100", "PHYS-3100", "PHYS-3300"]);
+        let taken: HashSet<&CourseCode> = taken_codes.iter().collect();
+        let credits = |c: &CourseCode| if *c == code("PHYS-3300") { 1 } else { 3 };
+        let courses = |num, list: &[&str]| Elective::Courses {
+            num,
+            opts: codes(list),
+        };
+
+        assert!(
+            courses(2, &["PHYS-2100", "PHYS-3100", "PHYS-4100"]).is_satisfied(&taken, &credits)
+        );
+        assert!(!courses(2, &["PHYS-2100", "PHYS-4100"]).is_satisfied(&taken, &credits));
+        // Credits add up what each course carries: 3 + 1 falls short of 5
+        let lab_credits = |num| Elective::Credits {
+            num,
+            opts: codes(&["PHYS-3100", "PHYS-3300"]),
+        };
+        assert!(lab_credits(4).is_satisfied(&taken, &credits));
+        assert!(!lab_credits(5).is_satisfied(&taken, &credits));
+        // A sequence needs every course of one of its lists
+        let sequence = Elective::Sequence(vec![
+            codes(&["PHYS-2100", "PHYS-4100"]),
+            codes(&["PHYS-3100", "PHYS-3300"]),
+        ]);
+        assert!(sequence.is_satisfied(&taken, &credits));
+        let unmet = courses(1, &["PHYS-4100"]);
+        assert!(Elective::Or(vec![unmet.clone(), sequence.clone()]).is_satisfied(&taken, &credits));
+        assert!(!Elective::And(vec![unmet, sequence]).is_satisfied(&taken, &credits));
+    }
+
+    #[test]
+    fn semesters_outside_their_credit_limits_are_reported() {
+        let courses: serde_json::Map<String, serde_json::Value> = (1..=7)
+            .map(|n| {
+                (
+                    format!("HIST-{}00", n),
+                    serde_json::json!(["History", 3, "Both"]),
+                )
+            })
+            .collect();
+        let catalog = catalog(serde_json::json!({ "courses": courses }));
+        let hist = |ns: &[u32]| ns.iter().map(|n| code(&format!("HIST-{}00", n))).collect();
+        let mut sched = schedule(
+            catalog,
+            &[],
+            vec![
+                hist(&[1]),
+                hist(&[2, 3]),
+                hist(&[1, 2, 3, 4, 5, 6, 7]),
+                hist(&[1, 2, 3, 4, 5, 6, 7]),
+            ],
+        );
+        sched.locked_semesters = 1;
+        // An approved overload in the last semester
+        let overload = CreditLimits { min: 12, max: 21 };
+        sched.credit_policy.semesters.insert(3, overload);
+
+        let mut report = ValidationReport::default();
+        sched.validate_credit_loads(&mut report);
+        // The locked first semester is history, and the last is within its overload
+        assert_eq!(
+            report.credit_violations,
+            vec![
+                CreditLoadViolation {
+                    semester: 1,
+                    credits: 6,
+                    limits: CreditLimits::default(),
+                },
+                CreditLoadViolation {
+                    semester: 2,
+                    credits: 21,
+                    limits: CreditLimits::default(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn replanning_keeps_the_history_as_given() {
+        let catalog = catalog(serde_json::json!({
+            "programs": [
+                {"name": "BS Chemistry", "kind": "Major",
+                 "semesters": [["CHEM-1200"], ["CHEM-1300"], ["CHEM-2100"]]}
+            ],
+            "prereqs": {"CHEM-1300": {"PreCourse": "CHEM-1200"}},
+            "courses": {
+                "CHEM-1200": ["General Chemistry I", 4, "Both"],
+                "CHEM-1300": ["General Chemistry II", 4, "Both"],
+                "CHEM-2100": ["Analytical Chemistry", 4, "Both"],
+                "ART-1000": ["Drawing", 3, "Both"]
+            }
+        }));
+        let mut options = ScheduleOptions::starting(Term::new(Season::Fall, 2025));
+        options.credit_policy.default = CreditLimits { min: 0, max: 18 };
+        // The student took an elective instead of following the template's first semester
+        let history = vec![vec![code("ART-1000")]];
+        let sched =
+            replan_schedule(vec!["BS Chemistry"], catalog, options, history.clone()).unwrap();
+
+        assert_eq!(sched.locked_semesters, 1);
+        assert_eq!(sched.history(), history.as_slice());
+        // The overdue template course is planned after the history, ahead of what builds on it
+        let semester_of = |c: &str| sched.courses.iter().position(|sem| sem.contains(&code(c)));
+        let chem_1200 = semester_of("CHEM-1200").unwrap();
+        assert!(chem_1200 >= 1);
+        assert!(semester_of("CHEM-1300").unwrap() > chem_1200);
+        assert!(semester_of("CHEM-2100").unwrap() >= 1);
+        assert!(sched.validate().unwrap().is_valid());
     }
 }
@@ -0,0 +1,155 @@
+use savefile_derive::Savefile;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::schedule::{CourseTermOffering, CreditPolicy};
+
+/// Seasons in calendar order; the winter session falls in January, between Fall and Spring.
+#[derive(
+    Savefile, Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord,
+)]
+pub enum Season {
+    Winter,
+    Spring,
+    Summer,
+    Fall,
+}
+
+impl std::str::FromStr for Season {
+    type Err = anyhow::Error;
+
+    /// A season by name, ignoring case, e.g. `fall` or `Spring`.
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "winter" => Ok(Season::Winter),
+            "spring" => Ok(Season::Spring),
+            "summer" => Ok(Season::Summer),
+            "fall" => Ok(Season::Fall),
+            _ => Err(anyhow::anyhow!("Invalid season: {}", s)),
+        }
+    }
+}
+
+/// A concrete term, e.g. Fall 2025 or Summer 2026. `year` is the calendar year the term is in.
+#[derive(
+    Savefile, Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord,
+)]
+pub struct Term {
+    pub year: u32,
+    pub season: Season,
+}
+
+impl Term {
+    pub fn new(season: Season, year: u32) -> Self {
+        Term { year, season }
+    }
+
+    /// The academic year the term belongs to, named by the calendar year of its Fall.
+    pub fn academic_year(&self) -> u32 {
+        match self.season {
+            Season::Fall => self.year,
+            Season::Winter | Season::Spring | Season::Summer => self.year - 1,
+        }
+    }
+
+    /// Fall and Spring semesters; Summer and Winter are optional sessions.
+    pub fn is_regular(&self) -> bool {
+        matches!(self.season, Season::Fall | Season::Spring)
+    }
+
+    /// The following term, including Summer and Winter sessions only if the policy opts into them.
+    pub fn next(&self, policy: &CreditPolicy) -> Term {
+        match self.season {
+            Season::Fall if policy.winter.is_some() => Term::new(Season::Winter, self.year + 1),
+            Season::Fall => Term::new(Season::Spring, self.year + 1),
+            Season::Winter => Term::new(Season::Spring, self.year),
+            Season::Spring if policy.summer.is_some() => Term::new(Season::Summer, self.year),
+            Season::Spring | Season::Summer => Term::new(Season::Fall, self.year),
+        }
+    }
+
+    /// Whether a course with `offering` may be taken this term. Courses with no listed offering
+    /// are assumed to run every Fall and Spring. Year parity follows the term's own year, so it
+    /// is anchored on the student's start term rather than on semester indices.
+    pub fn offers(&self, offering: Option<&CourseTermOffering>) -> bool {
+        match offering {
+            Some(CourseTermOffering::Fall) => self.season == Season::Fall,
+            Some(CourseTermOffering::Spring) => self.season == Season::Spring,
+            Some(CourseTermOffering::Summer) => self.season == Season::Summer,
+            Some(CourseTermOffering::Winter) => self.season == Season::Winter,
+            Some(CourseTermOffering::FallOdd) => {
+                self.season == Season::Fall && !self.year.is_multiple_of(2)
+            }
+            Some(CourseTermOffering::FallEven) => {
+                self.season == Season::Fall && self.year.is_multiple_of(2)
+            }
+            Some(CourseTermOffering::SpringOdd) => {
+                self.season == Season::Spring && !self.year.is_multiple_of(2)
+            }
+            Some(CourseTermOffering::SpringEven) => {
+                self.season == Season::Spring && self.year.is_multiple_of(2)
+            }
+            Some(CourseTermOffering::OddYears) => {
+                self.is_regular() && !self.academic_year().is_multiple_of(2)
+            }
+            Some(CourseTermOffering::EvenYears) => {
+                self.is_regular() && self.academic_year().is_multiple_of(2)
+            }
+            Some(CourseTermOffering::Both) => self.is_regular(),
+            Some(CourseTermOffering::Discretion) => self.is_regular(), // allowed, but may change in future
+            Some(CourseTermOffering::Infrequently) => self.is_regular(), // allowed, but may change in future
+            None => self.is_regular(),
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {}", self.season, self.year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn odd_and_even_terms_follow_the_calendar_year() {
+        let fall_2025 = Term::new(Season::Fall, 2025);
+        let spring_2026 = Term::new(Season::Spring, 2026);
+        assert!(fall_2025.offers(Some(&CourseTermOffering::FallOdd)));
+        assert!(!fall_2025.offers(Some(&CourseTermOffering::FallEven)));
+        assert!(spring_2026.offers(Some(&CourseTermOffering::SpringEven)));
+        assert!(!spring_2026.offers(Some(&CourseTermOffering::SpringOdd)));
+        assert!(!Term::new(Season::Fall, 2026).offers(Some(&CourseTermOffering::FallOdd)));
+    }
+
+    #[test]
+    fn odd_and_even_years_follow_the_academic_year() {
+        // Fall 2025 and Spring 2026 are both in academic year 2025
+        for term in [
+            Term::new(Season::Fall, 2025),
+            Term::new(Season::Spring, 2026),
+        ] {
+            assert!(term.offers(Some(&CourseTermOffering::OddYears)));
+            assert!(!term.offers(Some(&CourseTermOffering::EvenYears)));
+        }
+        assert!(Term::new(Season::Fall, 2026).offers(Some(&CourseTermOffering::EvenYears)));
+        assert!(!Term::new(Season::Summer, 2026).offers(Some(&CourseTermOffering::OddYears)));
+    }
+
+    #[test]
+    fn unlisted_courses_run_in_regular_semesters_only() {
+        assert!(Term::new(Season::Spring, 2026).offers(None));
+        assert!(!Term::new(Season::Winter, 2026).offers(None));
+        assert!(!Term::new(Season::Summer, 2026).offers(None));
+    }
+
+    #[test]
+    fn seasons_parse_by_name_ignoring_case() {
+        assert_eq!("fall".parse::<Season>().unwrap(), Season::Fall);
+        assert_eq!("Spring".parse::<Season>().unwrap(), Season::Spring);
+        assert_eq!("SUMMER".parse::<Season>().unwrap(), Season::Summer);
+        assert!("autumn".parse::<Season>().is_err());
+    }
+}
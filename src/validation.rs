@@ -0,0 +1,245 @@
+use std::fmt;
+
+use crate::prereqs::CourseReq;
+use crate::schedule::{CourseCode, CourseTermOffering, CreditLimits};
+use crate::terms::Term;
+
+/// Everything wrong with a schedule; empty when the schedule is valid.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Program template courses that are neither planned nor completed, as (program, course)
+    pub missing_program_courses: Vec<(String, CourseCode)>,
+    /// Programs whose electives aren't satisfied
+    pub unmet_electives: Vec<String>,
+    pub shared_credit_violations: Vec<SharedCreditViolation>,
+    pub prereq_violations: Vec<PrereqViolation>,
+    pub credit_violations: Vec<CreditLoadViolation>,
+    pub offering_violations: Vec<OfferingViolation>,
+    pub unmet_geneds: Vec<UnmetGenEd>,
+    pub overlap_violations: Vec<OverlapViolation>,
+}
+
+/// Two programs that share more credits than one of them allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedCreditViolation {
+    pub programs: (String, String),
+    pub credits: u32,
+    pub max: u32,
+}
+
+/// A planned course whose prerequisites aren't met by the semester it's planned in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrereqViolation {
+    pub course: CourseCode,
+    /// Index into `Schedule::courses`
+    pub semester: usize,
+    pub req: CourseReq,
+}
+
+/// A planned semester whose credit load falls outside its limits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreditLoadViolation {
+    /// Index into `Schedule::courses`
+    pub semester: usize,
+    pub credits: u32,
+    pub limits: CreditLimits,
+}
+
+/// A planned course placed in a term it isn't offered in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfferingViolation {
+    pub course: CourseCode,
+    pub term: Term,
+    /// `None` for courses missing from the catalog
+    pub offering: Option<CourseTermOffering>,
+}
+
+/// A gened that the schedule doesn't satisfy, and what it still needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnmetGenEd {
+    pub name: String,
+    pub missing: Shortfall,
+}
+
+/// What a requirement still needs to be satisfied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shortfall {
+    /// All of these courses
+    AllOf(Vec<CourseCode>),
+    /// `num` more courses from `from`
+    Courses { num: usize, from: Vec<CourseCode> },
+    /// `num` more credits from `from`
+    Credits { num: u32, from: Vec<CourseCode> },
+    /// Courses from `num` more stems, chosen from `from`
+    Stems { num: usize, from: Vec<CourseCode> },
+}
+
+/// A gened that is met on its own, but only by counting courses toward more geneds than the
+/// catalog's overlap policy allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverlapViolation {
+    /// A Core gened that can only be met with courses already counted toward the most Core geneds allowed
+    Core { name: String },
+    /// A Foundation that can only be met with courses already counted toward another Foundation
+    Foundation { name: String },
+    /// A Skill & Perspective that can only be met with courses already counted toward the most
+    /// Skills & Perspectives allowed
+    SkillAndPerspective { name: String },
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.missing_program_courses.is_empty()
+            && self.unmet_electives.is_empty()
+            && self.shared_credit_violations.is_empty()
+            && self.prereq_violations.is_empty()
+            && self.credit_violations.is_empty()
+            && self.offering_violations.is_empty()
+            && self.unmet_geneds.is_empty()
+            && self.overlap_violations.is_empty()
+    }
+
+    /// One line per problem, in a stable order.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for (program, course) in &self.missing_program_courses {
+            lines.push(format!("{}: missing required course {}", program, course));
+        }
+        for program in &self.unmet_electives {
+            lines.push(format!("{}: electives not satisfied", program));
+        }
+        for violation in &self.shared_credit_violations {
+            lines.push(violation.to_string());
+        }
+        for violation in &self.prereq_violations {
+            lines.push(violation.to_string());
+        }
+        for violation in &self.credit_violations {
+            lines.push(violation.to_string());
+        }
+        for violation in &self.offering_violations {
+            lines.push(violation.to_string());
+        }
+        for gened in &self.unmet_geneds {
+            lines.push(gened.to_string());
+        }
+        for overlap in &self.overlap_violations {
+            lines.push(overlap.to_string());
+        }
+        lines
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            return write!(f, "Schedule is valid");
+        }
+        write!(f, "Schedule is invalid:")?;
+        for line in self.lines() {
+            write!(f, "\n  - {}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SharedCreditViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} and {} share {} credits, more than the {} allowed",
+            self.programs.0, self.programs.1, self.credits, self.max
+        )
+    }
+}
+
+impl fmt::Display for PrereqViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} in semester {}: prerequisites not met ({})",
+            self.course,
+            self.semester + 1,
+            self.req
+        )
+    }
+}
+
+impl fmt::Display for CreditLoadViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "semester {} has {} credits, outside the {}-{} credit limits",
+            self.semester + 1,
+            self.credits,
+            self.limits.min,
+            self.limits.max
+        )
+    }
+}
+
+impl fmt::Display for OfferingViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.offering {
+            Some(offering) => write!(
+                f,
+                "{} is not offered in {} (offered {:?})",
+                self.course, self.term, offering
+            ),
+            None => write!(f, "{} is not offered in {}", self.course, self.term),
+        }
+    }
+}
+
+impl fmt::Display for UnmetGenEd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: needs {}", self.name, self.missing)
+    }
+}
+
+fn join_codes(codes: &[CourseCode]) -> String {
+    codes
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for Shortfall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shortfall::AllOf(codes) => write!(f, "{}", join_codes(codes)),
+            Shortfall::Courses { num, from } => {
+                write!(f, "{} more of {}", num, join_codes(from))
+            }
+            Shortfall::Credits { num, from } => {
+                write!(f, "{} more credits from {}", num, join_codes(from))
+            }
+            Shortfall::Stems { num, from } => {
+                write!(f, "{} more disciplines from {}", num, join_codes(from))
+            }
+        }
+    }
+}
+
+impl fmt::Display for OverlapViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverlapViolation::Core { name } => write!(
+                f,
+                "{}: only met by courses already counted toward as many Core geneds as allowed",
+                name
+            ),
+            OverlapViolation::Foundation { name } => write!(
+                f,
+                "{}: only met by courses already counted toward another Foundation",
+                name
+            ),
+            OverlapViolation::SkillAndPerspective { name } => write!(
+                f,
+                "{}: only met by courses already counted toward as many Skills & Perspectives as allowed",
+                name
+            ),
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::schedule::Schedule;
+use crate::validation::ValidationReport;
 use crate::{SAVEFILE_VERSION, TEMPLATE_PNG};
 use anyhow::Result;
-use rust_xlsxwriter::{Format, FormatAlign, Image, Workbook, Worksheet};
+use rust_xlsxwriter::{Format, FormatAlign, Image, Note, Workbook, Worksheet};
 use savefile::save_to_mem;
 use std::path::PathBuf;
 
@@ -9,28 +10,69 @@ fn pretty_print_sched_to_sheet(sched: &Schedule, sheet: &mut Worksheet) -> Resul
     let semesters = sched.courses.len();
     let format = Format::new().set_align(FormatAlign::Center);
 
+    // Prior credit gets its own leading column pair; semester columns shift right past it
+    let first_col = if sched.prior_credit.is_empty() { 0 } else { 2 };
+    if !sched.prior_credit.is_empty() {
+        sheet.merge_range(0, 0, 0, 1, "Prior Credit", &format)?;
+        for (row_idx, credit) in sched.prior_credit.iter().enumerate() {
+            sheet.write_string((row_idx + 1) as u32, 0, credit.to_string())?;
+            sheet.write_string((row_idx + 1) as u32, 1, credit.credits.to_string())?;
+        }
+    }
+
     for col_idx in 0..semesters {
         sheet.merge_range(
             0,
-            (col_idx * 2) as u16,
+            (first_col + col_idx * 2) as u16,
             0,
-            ((col_idx * 2) + 1) as u16,
-            &format!("Semester {}", col_idx + 1),
+            (first_col + (col_idx * 2) + 1) as u16,
+            &sched.terms[col_idx].to_string(),
             &format,
         )?;
     }
 
+    let consent_courses = sched.consent_dependent_courses();
+    let at_risk_courses = sched.at_risk_courses();
     for (col_idx, field) in sched.courses.iter().enumerate() {
         for (row_idx, val) in field.iter().enumerate() {
-            sheet.write_string((row_idx + 1) as u32, (col_idx * 2) as u16, val.to_string())?;
             sheet.write_string(
                 (row_idx + 1) as u32,
-                ((col_idx * 2) + 1) as u16,
+                (first_col + col_idx * 2) as u16,
+                val.to_string(),
+            )?;
+            let mut notes = Vec::new();
+            if sched.programs.len() > 1
+                && let Some(programs) = sched.program_assignments.get(val)
+            {
+                notes.push(format!("Serves: {}", programs.join(", ")));
+            }
+            if let Some(geneds) = sched.gened_assignments.get(val) {
+                notes.push(format!("Counts toward: {}", geneds.join(", ")));
+            }
+            if consent_courses.contains(val) {
+                notes.push("Assumes instructor consent".to_string());
+            }
+            if at_risk_courses.contains(val) {
+                notes.push("At risk: offered at discretion or infrequently".to_string());
+            }
+            if !notes.is_empty() {
+                sheet.insert_note(
+                    (row_idx + 1) as u32,
+                    (first_col + col_idx * 2) as u16,
+                    &Note::new(notes.join("\n")),
+                )?;
+            }
+            sheet.write_string(
+                (row_idx + 1) as u32,
+                (first_col + (col_idx * 2) + 1) as u16,
                 sched
                     .catalog
                     .courses
                     .get(&val)
-                    .map(|(_, x, _)| x.map(|credits| credits.to_string()).unwrap_or("cr".into()))
+                    .map(|(_, x, _)| match x {
+                        Some(_) => sched.credits(val).to_string(),
+                        None => "cr".into(),
+                    })
                     .ok_or(anyhow::anyhow!("Course lookup not found: {}", val))?,
             )?;
 
@@ -42,6 +84,21 @@ fn pretty_print_sched_to_sheet(sched: &Schedule, sheet: &mut Worksheet) -> Resul
     Ok(())
 }
 
+fn write_validation_to_sheet(report: &ValidationReport, sheet: &mut Worksheet) -> Result<()> {
+    let bold = Format::new().set_bold();
+    if report.is_valid() {
+        sheet.write_string_with_format(0, 0, "Schedule is valid", &bold)?;
+    } else {
+        sheet.write_string_with_format(0, 0, "Schedule is invalid", &bold)?;
+        for (row_idx, line) in report.lines().iter().enumerate() {
+            sheet.write_string((row_idx + 1) as u32, 0, line)?;
+        }
+    }
+    sheet.autofit();
+
+    Ok(())
+}
+
 fn embed_schedule_in_sheet(sheet: &mut Worksheet, sched: &Schedule) -> Result<()> {
     sheet.insert_image(
         0,
@@ -82,6 +139,10 @@ pub fn save_schedule(fname: &PathBuf, sched: &Schedule) -> Result<()> {
     pretty_print_sched_to_sheet(&sched, schedule_sheet)?;
     schedule_sheet.protect();
 
+    let validation_sheet = workbook.add_worksheet().set_name("Validation")?;
+    write_validation_to_sheet(&sched.validate()?, validation_sheet)?;
+    validation_sheet.protect();
+
     let test_sheet = workbook.add_worksheet().set_name("Internals")?;
     embed_schedule_in_sheet(test_sheet, sched)?;
     test_sheet.protect();